@@ -0,0 +1,336 @@
+//! Coordinates and compass directions shared by the grid puzzles.
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Row-column position on a grid, top to bottom, left to right
+///
+/// Components are signed so that stepping off the edge of a grid yields a coordinate that
+/// is simply not contained in it, rather than an underflow. The same type doubles as the
+/// offset between two coordinates, so it supports the usual vector operations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Coord {
+    pub const ORIGIN: Coord = Coord::new(0, 0);
+
+    pub const fn new(row: i32, col: i32) -> Self {
+        Self { row, col }
+    }
+
+    /// Coordinate one step away in the given direction
+    pub fn step(self, dir: impl Into<Direction8>) -> Self {
+        self + dir.into().delta()
+    }
+
+    /// Taxicab distance: the number of orthogonal steps between two coordinates
+    pub fn manhattan(self, other: Self) -> u32 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// King's-move distance: the number of steps between two coordinates when diagonal
+    /// steps are allowed
+    pub fn chebyshev(self, other: Self) -> u32 {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl From<(i32, i32)> for Coord {
+    fn from((row, col): (i32, i32)) -> Self {
+        Self::new(row, col)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Coord::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coord::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Self::Output {
+        Coord::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i32> for Coord {
+    type Output = Coord;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Coord::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// One of the four orthogonal compass directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from up
+    pub const ALL: [Direction4; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Offset of a single step in this direction
+    pub const fn delta(self) -> Coord {
+        match self {
+            Self::Up => Coord::new(-1, 0),
+            Self::Right => Coord::new(0, 1),
+            Self::Down => Coord::new(1, 0),
+            Self::Left => Coord::new(0, -1),
+        }
+    }
+
+    /// Direction after a quarter turn clockwise
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// Direction after a quarter turn anticlockwise
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    /// Direction after a half turn
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+}
+
+impl Display for Direction4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Up => "up",
+                Self::Right => "right",
+                Self::Down => "down",
+                Self::Left => "left",
+            }
+        )
+    }
+}
+
+/// One of the eight compass directions, including diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from up
+    pub const ALL: [Direction8; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Offset of a single step in this direction
+    pub const fn delta(self) -> Coord {
+        match self {
+            Self::Up => Coord::new(-1, 0),
+            Self::UpRight => Coord::new(-1, 1),
+            Self::Right => Coord::new(0, 1),
+            Self::DownRight => Coord::new(1, 1),
+            Self::Down => Coord::new(1, 0),
+            Self::DownLeft => Coord::new(1, -1),
+            Self::Left => Coord::new(0, -1),
+            Self::UpLeft => Coord::new(-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Direction after an eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Direction after an eighth of a turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Direction after a half turn
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Whether this is one of the four orthogonal directions
+    pub fn is_orthogonal(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+        match dir {
+            Direction4::Up => Self::Up,
+            Direction4::Right => Self::Right,
+            Direction4::Down => Self::Down,
+            Direction4::Left => Self::Left,
+        }
+    }
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Up => "up",
+                Self::UpRight => "up-right",
+                Self::Right => "right",
+                Self::DownRight => "down-right",
+                Self::Down => "down",
+                Self::DownLeft => "down-left",
+                Self::Left => "left",
+                Self::UpLeft => "up-left",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod geometry_tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_ops() {
+        let a = Coord::new(3, 4);
+        let b = Coord::new(5, 1);
+
+        assert_eq!(a + b, Coord::new(8, 5));
+        assert_eq!(a - b, Coord::new(-2, 3));
+        assert_eq!(-a, Coord::new(-3, -4));
+        assert_eq!(a * 3, Coord::new(9, 12));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test_case::test_case(Coord::new(0, 0), Coord::new(3, -4) => (7, 4))]
+    #[test_case::test_case(Coord::new(2, 2), Coord::new(2, 2) => (0, 0))]
+    #[test_case::test_case(Coord::new(-1, 5), Coord::new(1, 4) => (3, 2))]
+    fn test_distances(a: Coord, b: Coord) -> (u32, u32) {
+        (a.manhattan(b), a.chebyshev(b))
+    }
+
+    #[test_case::test_case(Coord::new(5, 5), Direction8::Right => Coord::new(5, 6))]
+    #[test_case::test_case(Coord::new(5, 5), Direction8::Up => Coord::new(4, 5))]
+    #[test_case::test_case(Coord::new(5, 5), Direction8::DownLeft => Coord::new(6, 4))]
+    #[test_case::test_case(Coord::new(0, 0), Direction8::UpLeft => Coord::new(-1, -1) ; "steps off the edge")]
+    fn test_step(c: Coord, dir: Direction8) -> Coord {
+        c.step(dir)
+    }
+
+    #[test]
+    fn test_direction4_turns() {
+        for dir in Direction4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.delta() + dir.opposite().delta(), Coord::ORIGIN);
+        }
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+    }
+
+    #[test]
+    fn test_direction8_turns() {
+        for dir in Direction8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(
+                dir.turn_right().turn_right().turn_right().turn_right(),
+                dir.opposite()
+            );
+            assert_eq!(dir.delta() + dir.opposite().delta(), Coord::ORIGIN);
+        }
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+    }
+
+    #[test]
+    fn test_direction4_matches_direction8() {
+        for dir in Direction4::ALL {
+            let dir8 = Direction8::from(dir);
+            assert!(dir8.is_orthogonal());
+            assert_eq!(dir.delta(), dir8.delta());
+            assert_eq!(
+                Direction8::from(dir.turn_right()),
+                dir8.turn_right().turn_right()
+            );
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! Dense, row-major grids parsed from character art.
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Coord, Direction4, Direction8},
+    ParseError,
+};
+
+/// Rectangular grid of cells stored densely in row-major order
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid of the given size with every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parse character art into a grid, converting each character with `f`
+    ///
+    /// `expected` describes the characters `f` accepts, and is used to report any character
+    /// it rejects. Every line must be the same length as the first.
+    pub fn parse(
+        raw: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = raw
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::eof(raw, "a row of the grid"))?
+            .chars()
+            .count();
+
+        let mut cells = Vec::with_capacity(raw.len());
+        let mut height = 0;
+        for line in raw.lines() {
+            if line.chars().count() != width {
+                return Err(ParseError::at(
+                    raw,
+                    line,
+                    format!("a row of {width} characters"),
+                ));
+            }
+            for (idx, c) in line.char_indices() {
+                let cell = f(c)
+                    .ok_or_else(|| ParseError::at(raw, &line[idx..idx + c.len_utf8()], expected))?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the coordinate lies within the grid
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.row >= 0
+            && coord.col >= 0
+            && (coord.row as usize) < self.height
+            && (coord.col as usize) < self.width
+    }
+
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then(|| coord.row as usize * self.width + coord.col as usize)
+    }
+
+    fn coord_of(&self, idx: usize) -> Coord {
+        Coord::new((idx / self.width) as i32, (idx % self.width) as i32)
+    }
+
+    /// Cell at the given coordinate, or `None` if it is off the grid
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|idx| &self.cells[idx])
+    }
+
+    /// Mutable cell at the given coordinate, or `None` if it is off the grid
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Every coordinate on the grid in row-major order
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.cells.len()).map(|idx| self.coord_of(idx))
+    }
+
+    /// Every cell on the grid with its coordinate, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.coord_of(idx), cell))
+    }
+
+    /// Every cell on the grid with its coordinate, in row-major order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, cell)| (Coord::new((idx / width) as i32, (idx % width) as i32), cell))
+    }
+
+    /// Coordinate of the first cell, in row-major order, matching the predicate
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.cells
+            .iter()
+            .position(pred)
+            .map(|idx| self.coord_of(idx))
+    }
+
+    /// Convert every cell, keeping the shape of the grid
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Copy of the grid turned a quarter turn clockwise
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|col| {
+                (0..self.height)
+                    .rev()
+                    .map(move |row| self.cells[row * self.width + col].clone())
+            })
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Orthogonal neighbours of a cell that lie on the grid, clockwise from up
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        Direction4::ALL.into_iter().filter_map(move |dir| {
+            let neighbour = coord.step(dir);
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    /// Orthogonal and diagonal neighbours of a cell that lie on the grid, clockwise from up
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        Direction8::ALL.into_iter().filter_map(move |dir| {
+            let neighbour = coord.step(dir);
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    /// Cells from `start` (inclusive) stepping by `step` until leaving the grid
+    pub fn ray(&self, start: Coord, step: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        std::iter::successors(Some(start), move |&c| Some(c + step))
+            .map_while(|c| self.get(c).map(|cell| (c, cell)))
+    }
+
+    /// Cells of a single row, left to right
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.cells[row * self.width..(row + 1) * self.width].iter()
+    }
+
+    /// Cells of a single column, top to bottom
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    /// Every row, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Every column, left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every diagonal running down and to the right, starting from the bottom-left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height as i32)
+            .rev()
+            .map(|row| Coord::new(row, 0))
+            .chain((1..self.width as i32).map(|col| Coord::new(0, col)));
+        starts.map(|start| {
+            self.ray(start, Direction8::DownRight.delta())
+                .map(|(_, cell)| cell)
+        })
+    }
+
+    /// Every diagonal running down and to the left, starting from the top-left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last_col = self.width as i32 - 1;
+        let starts = (0..self.width as i32)
+            .map(|col| Coord::new(0, col))
+            .chain((1..self.height as i32).map(move |row| Coord::new(row, last_col)));
+        starts.map(|start| {
+            self.ray(start, Direction8::DownLeft.delta())
+                .map(|(_, cell)| cell)
+        })
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("coordinate {coord} is off the grid"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("coordinate {coord} is off the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    const SAMPLE: &str = "abc\ndef";
+
+    fn sample() -> Grid<char> {
+        Grid::parse(SAMPLE, "any character", Some).expect("could not parse grid")
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Coord::new(1, 2)), Some(&'f'));
+        assert_eq!(grid[Coord::new(0, 1)], 'b');
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("ab\nabc", "any character", Some).expect_err("rows are ragged");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 1, "abc"));
+
+        let err =
+            Grid::parse("12\n3x", "a digit", |c| c.to_digit(10)).expect_err("x is not a digit");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "x"));
+    }
+
+    #[test_case::test_case(Coord::new(0, 0) => true)]
+    #[test_case::test_case(Coord::new(1, 2) => true)]
+    #[test_case::test_case(Coord::new(-1, 0) => false)]
+    #[test_case::test_case(Coord::new(0, -1) => false)]
+    #[test_case::test_case(Coord::new(2, 0) => false)]
+    #[test_case::test_case(Coord::new(0, 3) => false)]
+    fn test_contains(coord: Coord) -> bool {
+        sample().contains(coord)
+    }
+
+    #[test_case::test_case(Coord::new(0, 0) => vec!['b', 'd'] ; "corner")]
+    #[test_case::test_case(Coord::new(1, 1) => vec!['b', 'f', 'd'] ; "bottom edge")]
+    fn test_neighbours4(coord: Coord) -> Vec<char> {
+        sample().neighbours4(coord).map(|(_, c)| *c).collect()
+    }
+
+    #[test_case::test_case(Coord::new(0, 0) => vec!['b', 'e', 'd'] ; "corner")]
+    #[test_case::test_case(Coord::new(1, 1) => vec!['b', 'c', 'f', 'd', 'a'] ; "bottom edge")]
+    fn test_neighbours8(coord: Coord) -> Vec<char> {
+        sample().neighbours8(coord).map(|(_, c)| *c).collect()
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+        let collect = |it: &mut dyn Iterator<Item = &char>| it.collect::<String>();
+
+        assert_eq!(
+            grid.rows().map(|mut r| collect(&mut r)).collect::<Vec<_>>(),
+            vec!["abc", "def"]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|mut c| collect(&mut c))
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            vec!["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            vec!["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = sample();
+        let ray = grid
+            .ray(Coord::new(0, 0), Direction8::Right.delta())
+            .map(|(_, c)| *c)
+            .collect::<String>();
+        assert_eq!(ray, "abc");
+    }
+
+    #[test]
+    fn test_position_and_iter() {
+        let grid = sample();
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Coord::new(1, 1)));
+        assert_eq!(grid.coords().count(), 6);
+        assert_eq!(grid.iter().last(), Some((Coord::new(1, 2), &'f')));
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let rotated = sample().rotate_right();
+        assert_eq!(rotated.to_string(), "da\neb\nfc");
+        assert_eq!(
+            rotated.rotate_right().rotate_right().rotate_right(),
+            sample()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = sample().map(|c| c.to_ascii_uppercase());
+        assert_eq!(grid.to_string(), "ABC\nDEF");
+    }
+}
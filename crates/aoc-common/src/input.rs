@@ -0,0 +1,172 @@
+//! Loading of puzzle inputs at runtime.
+//!
+//! Puzzle inputs are personal and are not committed, so they are read when a solution is
+//! run rather than compiled into each day. By default they live in a per-user cache
+//! directory, under `{year}/day{N}/input.txt`.
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where to read a day's puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Read the input from standard input
+    Stdin,
+    /// Read the input from the given file
+    File(PathBuf),
+    /// Read `day{N}.txt` from the given directory of inputs
+    Dir(PathBuf),
+    /// Read `{year}/day{N}/input.txt` from the given cache directory
+    Cache(PathBuf),
+}
+
+impl InputSource {
+    /// Path the input will be read from for a given day, if it comes from a file
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Stdin => None,
+            Self::File(path) => Some(path.clone()),
+            Self::Dir(dir) => Some(dir.join(format!("day{day}.txt"))),
+            Self::Cache(root) => Some(cached_input_path(root, day)),
+        }
+    }
+}
+
+/// Failure to load a day's puzzle input
+#[derive(Debug)]
+pub enum InputError {
+    /// No input file exists at the expected location
+    NotFound { day: u8, path: PathBuf },
+    /// The input exists but could not be read
+    Io {
+        path: Option<PathBuf>,
+        err: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound { day, path } => write!(
+                f,
+                "no puzzle input for day {day} at {}; save it with `aoc input add`, pass --input <path> (or - for stdin), or set AOC_INPUTS_DIR",
+                path.display()
+            ),
+            Self::Io { path: Some(path), err } => {
+                write!(f, "could not read input {}: {err}", path.display())
+            }
+            Self::Io { path: None, err } => write!(f, "could not read input from stdin: {err}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotFound { .. } => None,
+            Self::Io { err, .. } => Some(err),
+        }
+    }
+}
+
+/// Directory of a day's crate
+fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-common should live in the crates directory")
+        .join(format!("day{day}"))
+}
+
+/// Location of a day's puzzle input within a cache directory
+pub fn cached_input_path(root: &Path, day: u8) -> PathBuf {
+    root.join(crate::YEAR.to_string())
+        .join(format!("day{day}"))
+        .join("input.txt")
+}
+
+/// Sample inputs from a day's puzzle description, in `samples/*.txt` within the day's crate
+pub fn sample_paths(day: u8) -> io::Result<Vec<PathBuf>> {
+    crate::samples::sample_paths(&day_dir(day).join("samples"))
+}
+
+/// Read a day's puzzle input from the given source
+pub fn load(day: u8, source: &InputSource) -> Result<String, InputError> {
+    let Some(path) = source.path(day) else {
+        let mut raw = String::new();
+        io::stdin()
+            .read_to_string(&mut raw)
+            .map_err(|err| InputError::Io { path: None, err })?;
+        return Ok(raw);
+    };
+
+    std::fs::read_to_string(&path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound { day, path },
+        _ => InputError::Io {
+            path: Some(path),
+            err,
+        },
+    })
+}
+
+#[cfg(test)]
+mod input_tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(InputSource::Stdin.path(3), None);
+        assert_eq!(
+            InputSource::File("some/file.txt".into()).path(3),
+            Some(PathBuf::from("some/file.txt"))
+        );
+        assert_eq!(
+            InputSource::Dir("inputs".into()).path(3),
+            Some(PathBuf::from("inputs/day3.txt"))
+        );
+        assert_eq!(
+            InputSource::Cache("cache".into()).path(3),
+            Some(PathBuf::from("cache/2024/day3/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_sample_paths() {
+        let names = |day| {
+            sample_paths(day)
+                .expect("could not list samples")
+                .iter()
+                .map(|p| {
+                    p.file_name()
+                        .expect("sample should have a name")
+                        .to_string_lossy()
+                        .into_owned()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(3), vec!["sample1.txt", "sample2.txt", "tricky.txt"]);
+        assert_eq!(names(7), vec!["sample.txt"]);
+    }
+
+    #[test]
+    fn test_load_from_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("could not create dir");
+        std::fs::write(dir.join("day5.txt"), "1 2 3\n").expect("could not write input");
+
+        let raw = load(5, &InputSource::Dir(dir.clone())).expect("could not load input");
+        assert_eq!(raw, "1 2 3\n");
+
+        std::fs::remove_dir_all(dir).expect("could not remove dir");
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let err = load(25, &InputSource::File("does/not/exist.txt".into()))
+            .expect_err("input should be missing");
+        assert!(matches!(err, InputError::NotFound { day: 25, .. }));
+        assert!(err.to_string().contains("does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,88 @@
+//! Shared pieces used by every day of the Advent of Code 2024 solutions.
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod samples;
+
+use std::{fmt::Display, str::FromStr};
+
+pub use parse::ParseError;
+
+/// Year of the Advent of Code event these solutions are for
+pub const YEAR: u16 = 2024;
+
+/// A single day's puzzle solution.
+///
+/// The raw puzzle input is parsed once into [`Solution::Input`], which is then shared by
+/// both parts so that tooling can drive every day the same way.
+pub trait Solution {
+    /// Puzzle day (1-25)
+    const DAY: u8;
+
+    /// Parsed puzzle input shared by both parts
+    type Input;
+
+    /// Answer produced by each part
+    type Answer: Display;
+
+    /// Parse raw puzzle text into the day's input type
+    fn parse(raw: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solve the first part of the puzzle
+    fn part1(input: &Self::Input) -> Self::Answer;
+
+    /// Solve the second part of the puzzle
+    fn part2(input: &Self::Input) -> Self::Answer;
+
+    /// Solve the given part of the puzzle
+    fn solve(input: &Self::Input, part: Part) -> Self::Answer {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Describe the parsed input beyond the two answers, e.g. statistics or a breakdown of
+    /// how each answer was reached; most days have nothing to add
+    fn explain(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// One of the two parts of a day's puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::One => 1,
+                Self::Two => 2,
+            }
+        )
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("invalid part: {s}; expected 1 or 2")),
+        }
+    }
+}
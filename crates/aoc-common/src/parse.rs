@@ -0,0 +1,137 @@
+//! Errors for malformed puzzle input.
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Location and description of malformed puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the offending token, starting at 1
+    pub line: usize,
+    /// Column of the offending token in characters, starting at 1
+    pub column: usize,
+    /// Offending token; empty when input ended early
+    pub token: String,
+    /// What was expected in place of the token
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Create an error for `token`, which must be a slice of the full input `raw`
+    ///
+    /// Line and column are worked out from where the token sits within the input, so callers
+    /// can report errors from deep inside `lines()`/`split()` iterators without tracking
+    /// positions themselves.
+    pub fn at(raw: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(raw.as_ptr() as usize)
+            .filter(|&offset| offset + token.len() <= raw.len())
+            .expect("token should be a slice of the raw input");
+
+        let before = &raw[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            token: token.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Create an error for input that ended before something that was expected
+    pub fn eof(raw: &str, expected: impl Into<String>) -> Self {
+        Self::at(raw, &raw[raw.len()..], expected)
+    }
+
+    /// Render the offending line of `raw` with the token underlined by carets
+    pub fn snippet(&self, raw: &str) -> String {
+        let line = raw.lines().nth(self.line - 1).unwrap_or_default();
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = "^".repeat(self.token.chars().count().max(1));
+        format!(
+            "{gutter} |\n{line_no} | {line}\n{gutter} | {pad}{underline}",
+            line_no = self.line,
+            pad = " ".repeat(self.column - 1),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.token.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.token)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `token`, which must be a slice of the full input `raw`, reporting its location on failure
+pub fn parse_token<T: FromStr>(
+    raw: &str,
+    token: &str,
+    expected: impl Into<String>,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(raw, token, expected))
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    static RAW: &str = "1 2 3\n4 x5 6\n";
+
+    #[test]
+    fn test_at() {
+        let token = &RAW[8..10];
+        let err = ParseError::at(RAW, token, "a number");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.token, "x5");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found `x5`"
+        );
+    }
+
+    #[test]
+    fn test_eof() {
+        let err = ParseError::eof(RAW, "another line");
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected another line, found nothing"
+        );
+    }
+
+    #[test]
+    fn test_snippet() {
+        let err = ParseError::at(RAW, &RAW[8..10], "a number");
+        assert_eq!(err.snippet(RAW), "  |\n2 | 4 x5 6\n  |   ^^");
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let line = RAW.lines().nth(1).expect("missing line");
+        let tokens = line
+            .split_whitespace()
+            .map(|t| parse_token::<u32>(RAW, t, "a number"))
+            .collect::<Vec<_>>();
+        assert_eq!(tokens[0], Ok(4));
+        assert_eq!(tokens[1].as_ref().map_err(|e| e.column), Err(3));
+        assert_eq!(tokens[2], Ok(6));
+    }
+
+    #[test]
+    #[should_panic(expected = "slice of the raw input")]
+    fn test_at_panics_on_foreign_token() {
+        ParseError::at(RAW, "x5", "a number");
+    }
+}
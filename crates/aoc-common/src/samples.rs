@@ -0,0 +1,263 @@
+//! Sample inputs with their expected answers, checked by generated tests.
+//!
+//! Each day keeps the examples from its puzzle description in a `samples` directory. Every
+//! `<name>.txt` may have a sidecar `<name>.expected` listing the answers it should produce:
+//!
+//! ```text
+//! part1 = 3749
+//! part2 = 11387
+//! ```
+//!
+//! Either part may be left out, for samples that only apply to one part. A day's build
+//! script calls [`generate_tests`], and [`sample_tests!`](crate::sample_tests) then expands
+//! to one test per sample per expected part, so adding a sample is just adding its files.
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{ParseError, Part, Solution};
+
+/// Answers a sample is expected to produce, as listed in its `.expected` sidecar
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    /// Parse a sidecar of `part<N> = <answer>` lines; blank lines and `#` comments are ignored
+    pub fn parse(raw: &str) -> Result<Self, ParseError> {
+        let mut expected = Self::default();
+        for line in raw.lines() {
+            let content = line.split('#').next().unwrap_or_default().trim();
+            if content.is_empty() {
+                continue;
+            }
+            let (key, answer) = content
+                .split_once('=')
+                .ok_or_else(|| ParseError::at(raw, content, "`part1 = <answer>`"))?;
+            let (key, answer) = (key.trim(), answer.trim().trim_matches('"'));
+            let slot = match key {
+                "part1" => &mut expected.part1,
+                "part2" => &mut expected.part2,
+                _ => return Err(ParseError::at(raw, key, "`part1` or `part2`")),
+            };
+            *slot = Some(answer.to_owned());
+        }
+        Ok(expected)
+    }
+
+    /// Expected answer to the given part, if listed
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Every `*.txt` sample in a directory, in name order
+pub fn sample_paths(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+    Ok(paths)
+}
+
+/// Turn a sample's file name into the start of a valid test function name
+fn test_prefix(sample: &str) -> String {
+    let mut name = sample
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert_str(0, "sample_");
+    }
+    name
+}
+
+/// Turn a sample's file name into a valid test function name
+fn test_name(sample: &str, part: Part) -> String {
+    format!("{}_part{part}", test_prefix(sample))
+}
+
+/// Generate the source of one test per sample per expected part for the samples in `dir`
+fn tests_source(dir: &Path) -> Result<String, String> {
+    let mut source = String::new();
+    let paths = match sample_paths(dir) {
+        Ok(paths) => paths,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(source),
+        Err(e) => return Err(format!("could not list samples in {}: {e}", dir.display())),
+    };
+
+    // sanitizing can map different names to the same test, which wouldn't compile
+    let mut prefixes = HashMap::<String, PathBuf>::new();
+    for path in paths {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| format!("sample {} is not named in UTF-8", path.display()))?;
+        let sidecar = path.with_extension("expected");
+        let raw = match std::fs::read_to_string(&sidecar) {
+            Ok(raw) => raw,
+            // samples without expected answers are only used by hand-written tests
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("could not read {}: {e}", sidecar.display())),
+        };
+        let expected = Expected::parse(&raw).map_err(|e| {
+            format!(
+                "could not parse {}: {e}\n{}",
+                sidecar.display(),
+                e.snippet(&raw)
+            )
+        })?;
+        let prefix = test_prefix(name);
+        if let Some(other) = prefixes.insert(prefix.clone(), path.clone()) {
+            return Err(format!(
+                "samples {} and {} would both generate tests named {prefix}_part<N>; rename one of them",
+                other.display(),
+                path.display(),
+            ));
+        }
+
+        for part in Part::ALL {
+            let Some(answer) = expected.get(part) else {
+                continue;
+            };
+            let part_variant = match part {
+                Part::One => "One",
+                Part::Two => "Two",
+            };
+            let _ = writeln!(
+                source,
+                "#[test]\nfn {test}() {{\n    ::aoc_common::samples::check::<Day>({name:?}, include_str!({path:?}), ::aoc_common::Part::{part_variant}, {answer:?});\n}}\n",
+                test = test_name(name, part),
+                path = path.display().to_string(),
+            );
+        }
+    }
+
+    Ok(source)
+}
+
+/// Generate tests for the `samples` directory of the crate being built
+///
+/// Call this from a day's `build.rs`, and expand [`sample_tests!`](crate::sample_tests) in
+/// its `lib.rs` to include them.
+pub fn generate_tests() {
+    let manifest_dir =
+        std::env::var_os("CARGO_MANIFEST_DIR").expect("should be run from a build script");
+    let out_dir = std::env::var_os("OUT_DIR").expect("should be run from a build script");
+    let dir = Path::new(&manifest_dir).join("samples");
+
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let source = tests_source(&dir).unwrap_or_else(|e| panic!("{e}"));
+    std::fs::write(Path::new(&out_dir).join("sample_tests.rs"), source)
+        .expect("could not write sample tests");
+}
+
+/// Solve a part of a sample and check it against the expected answer
+///
+/// Called by the tests that [`sample_tests!`](crate::sample_tests) generates.
+pub fn check<S: Solution>(name: &str, raw: &str, part: Part, expected: &str) {
+    let input = S::parse(raw)
+        .unwrap_or_else(|e| panic!("could not parse sample {name}: {e}\n{}", e.snippet(raw)));
+    assert_eq!(
+        S::solve(&input, part).to_string(),
+        expected,
+        "day {} part {part} on sample {name}",
+        S::DAY
+    );
+}
+
+/// Expand to a test module checking a day's solution against every sample with expected
+/// answers, as generated by [`samples::generate_tests`](crate::samples::generate_tests)
+#[macro_export]
+macro_rules! sample_tests {
+    ($solution:ident) => {
+        #[cfg(test)]
+        mod sample_tests {
+            type Day = super::$solution;
+
+            include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod samples_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected() {
+        let expected =
+            Expected::parse("# from the puzzle\npart1 = 161\n\npart2 = \"48\"  # quoted\n")
+                .expect("could not parse expected answers");
+        assert_eq!(expected.get(Part::One), Some("161"));
+        assert_eq!(expected.get(Part::Two), Some("48"));
+
+        let expected = Expected::parse("part2 = 48").expect("could not parse expected answers");
+        assert_eq!(expected.get(Part::One), None);
+    }
+
+    #[test_case::test_case("part1 161" => (1, 1, "part1 161".to_owned()) ; "missing equals")]
+    #[test_case::test_case("part1 = 1\npart3 = 2" => (2, 1, "part3".to_owned()) ; "unknown part")]
+    fn test_parse_expected_error(raw: &str) -> (usize, usize, String) {
+        let err = Expected::parse(raw).expect_err("expected answers should be invalid");
+        (err.line, err.column, err.token)
+    }
+
+    #[test_case::test_case("sample", Part::One => "sample_part1")]
+    #[test_case::test_case("edge-Case", Part::Two => "edge_case_part2")]
+    #[test_case::test_case("2", Part::One => "sample_2_part1")]
+    fn test_test_name(sample: &str, part: Part) -> String {
+        test_name(sample, part)
+    }
+
+    #[test]
+    fn test_tests_source() {
+        let dir = std::env::temp_dir().join(format!("aoc-samples-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("could not create dir");
+        std::fs::write(dir.join("sample.txt"), "1 2 3\n").expect("could not write sample");
+        std::fs::write(dir.join("sample.expected"), "part2 = 6\n").expect("could not write");
+        std::fs::write(dir.join("unchecked.txt"), "4 5 6\n").expect("could not write sample");
+
+        let source = tests_source(&dir).expect("could not generate tests");
+        assert!(source.contains("fn sample_part2()"));
+        assert!(source.contains("::aoc_common::Part::Two, \"6\")"));
+        assert!(!source.contains("part1"));
+        assert!(!source.contains("unchecked"));
+
+        std::fs::remove_dir_all(&dir).expect("could not remove dir");
+        assert_eq!(tests_source(&dir), Ok(String::new()));
+    }
+
+    #[test]
+    fn test_tests_source_name_collision() {
+        let dir = std::env::temp_dir().join(format!("aoc-samples-clash-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("could not create dir");
+        for name in ["a-b", "a_b"] {
+            std::fs::write(dir.join(format!("{name}.txt")), "1\n").expect("could not write sample");
+            std::fs::write(dir.join(format!("{name}.expected")), "part1 = 1\n")
+                .expect("could not write");
+        }
+
+        let err = tests_source(&dir).expect_err("sample names should clash");
+        assert!(err.contains("a-b.txt"), "{err}");
+        assert!(err.contains("a_b.txt"), "{err}");
+        assert!(err.contains("a_b_part<N>"), "{err}");
+
+        std::fs::remove_dir_all(&dir).expect("could not remove dir");
+    }
+}
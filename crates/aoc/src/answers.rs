@@ -0,0 +1,159 @@
+//! Registry of known-correct answers, used to check solutions against real puzzle inputs.
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Part;
+
+/// Correct answers to each part of each day, as recorded in an `answers.toml` file:
+///
+/// ```toml
+/// [day7]
+/// part1 = 3749
+/// part2 = 11387
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Answers(BTreeMap<(u8, Part), String>);
+
+impl Answers {
+    /// Default location of the answers file, at the root of the workspace
+    pub(crate) fn default_path() -> PathBuf {
+        crate::workspace_root().join("answers.toml")
+    }
+
+    /// Load answers from a file; a missing file simply has no answers recorded
+    pub(crate) fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match std::fs::read_to_string(path) {
+            Ok(raw) => Self::parse(&raw)
+                .map_err(|e| format!("could not parse answers {}: {e}", path.display()).into()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read answers {}: {e}", path.display()).into()),
+        }
+    }
+
+    fn parse(raw: &str) -> Result<Self, String> {
+        let table = raw.parse::<toml::Table>().map_err(|e| e.to_string())?;
+        let mut answers = Self::default();
+
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid table [{day_key}]; expected [day<N>]"))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("{day_key} should be a table of part answers"))?;
+
+            for (part_key, answer) in parts {
+                let part = part_key
+                    .strip_prefix("part")
+                    .and_then(|p| p.parse::<Part>().ok())
+                    .ok_or_else(|| {
+                        format!("invalid key {day_key}.{part_key}; expected part1 or part2")
+                    })?;
+                let answer = match answer {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(n) => n.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "{day_key}.{part_key} should be a number or a string"
+                        ))
+                    }
+                };
+                answers.0.insert((day, part), answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    /// Recorded answer to a part of a day, if known
+    pub(crate) fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare an answer against the recorded one
+    pub(crate) fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+/// Outcome of checking an answer against the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Verdict {
+    /// The answer matches the recorded one
+    Pass,
+    /// The answer differs from the recorded one
+    Fail { expected: String },
+    /// No answer has been recorded for this part
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod answers_tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day7]
+part1 = 3749
+part2 = "11387"
+
+[day12]
+part1 = 1930
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).expect("could not parse answers");
+        assert_eq!(answers.get(7, Part::One), Some("3749"));
+        assert_eq!(answers.get(7, Part::Two), Some("11387"));
+        assert_eq!(answers.get(12, Part::One), Some("1930"));
+        assert_eq!(answers.get(12, Part::Two), None);
+    }
+
+    #[test_case::test_case("[seven]\npart1 = 1" => "invalid table [seven]; expected [day<N>]" ; "bad day")]
+    #[test_case::test_case("[day7]\npart3 = 1" => "invalid key day7.part3; expected part1 or part2" ; "bad part")]
+    #[test_case::test_case("[day7]\npart1 = 1.5" => "day7.part1 should be a number or a string" ; "bad answer")]
+    #[test_case::test_case("day7 = 1" => "day7 should be a table of part answers" ; "not a table")]
+    fn test_parse_error(raw: &str) -> String {
+        Answers::parse(raw).expect_err("answers should be invalid")
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).expect("could not parse answers");
+        assert_eq!(answers.check(7, Part::One, "3749"), Verdict::Pass);
+        assert_eq!(
+            answers.check(7, Part::Two, "11388"),
+            Verdict::Fail {
+                expected: "11387".to_owned()
+            }
+        );
+        assert_eq!(answers.check(12, Part::Two, "1206"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let answers = Answers::load(Path::new("does/not/exist.toml")).expect("missing is empty");
+        assert_eq!(answers, Answers::default());
+    }
+}
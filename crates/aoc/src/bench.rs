@@ -0,0 +1,431 @@
+//! Criterion-style timing of every day's parse and parts, with comparison against saved
+//! baselines so that regressions are caught.
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Display, Write},
+    io,
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{parse::parse_token, ParseError, Part};
+
+use crate::days::Day;
+
+/// A step of a day's solution that is timed on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    /// Every stage, in the order they run
+    const ALL: [Stage; 3] = [
+        Stage::Parse,
+        Stage::Solve(Part::One),
+        Stage::Solve(Part::Two),
+    ];
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Solve(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Self::Parse),
+            _ => s
+                .strip_prefix("part")
+                .ok_or_else(|| format!("invalid stage: {s}"))?
+                .parse()
+                .map(Self::Solve),
+        }
+    }
+}
+
+/// How long each stage is measured for
+pub(crate) struct BenchConfig {
+    /// Time spent sampling each stage; at least one sample is always taken
+    pub(crate) measurement_time: Duration,
+    /// Upper limit on the number of samples of each stage
+    pub(crate) max_samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            measurement_time: Duration::from_secs(1),
+            max_samples: 100,
+        }
+    }
+}
+
+/// Measurement time given as a number of seconds, rejecting negative, NaN or overflowing
+/// values that `Duration` can't hold
+pub(crate) fn parse_measurement_time(s: &str) -> Result<Duration, String> {
+    let secs = s
+        .parse::<f64>()
+        .map_err(|e| format!("invalid number of seconds: {e}"))?;
+    Duration::try_from_secs_f64(secs).map_err(|e| format!("invalid measurement time {s}: {e}"))
+}
+
+/// Identifies a single benchmark: one stage of one day against one input
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct BenchId {
+    pub(crate) day: u8,
+    /// Name of the input, e.g. `sample` or `input`
+    pub(crate) input: String,
+    pub(crate) stage: Stage,
+}
+
+/// Typical time taken by a single benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Measurement {
+    pub(crate) id: BenchId,
+    pub(crate) median: Duration,
+    pub(crate) samples: usize,
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    let mid = samples.len() / 2;
+    if samples.len().is_multiple_of(2) {
+        (samples[mid - 1] + samples[mid]) / 2
+    } else {
+        samples[mid]
+    }
+}
+
+/// Time the parse and each part of a day against a raw input
+///
+/// Every stage is run once to warm up before it is sampled.
+pub(crate) fn measure(
+    day: &Day,
+    input: &str,
+    raw: &str,
+    config: &BenchConfig,
+) -> Result<Vec<Measurement>, ParseError> {
+    Stage::ALL
+        .into_iter()
+        .map(|stage| {
+            let parts: &[Part] = match &stage {
+                Stage::Parse => &[],
+                Stage::Solve(part) => std::slice::from_ref(part),
+            };
+            let sample = || {
+                (day.run)(raw, parts).map(|result| match stage {
+                    Stage::Parse => result.parse_elapsed,
+                    Stage::Solve(_) => result.parts[0].elapsed,
+                })
+            };
+
+            sample()?;
+            let start = Instant::now();
+            let mut samples = vec![sample()?];
+            while samples.len() < config.max_samples && start.elapsed() < config.measurement_time {
+                samples.push(sample()?);
+            }
+
+            Ok(Measurement {
+                id: BenchId {
+                    day: day.day,
+                    input: input.to_owned(),
+                    stage,
+                },
+                samples: samples.len(),
+                median: median(samples),
+            })
+        })
+        .collect()
+}
+
+/// Median times from an earlier run, saved under a name to compare later runs against
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Baseline(BTreeMap<BenchId, Duration>);
+
+impl Baseline {
+    /// Directory baselines are saved in, under the workspace's target directory
+    fn dir() -> PathBuf {
+        crate::workspace_root().join("target").join("aoc-bench")
+    }
+
+    fn path(name: &str) -> PathBuf {
+        Self::dir().join(format!("{name}.tsv"))
+    }
+
+    /// Load a saved baseline
+    pub(crate) fn load(name: &str) -> Result<Self, Box<dyn Error>> {
+        let path = Self::path(name);
+        let raw = std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => format!(
+                "no baseline named {name} at {}; save one with --save-baseline {name}",
+                path.display()
+            ),
+            _ => format!("could not read baseline {}: {e}", path.display()),
+        })?;
+        Self::parse(&raw).map_err(|e| {
+            format!(
+                "could not parse baseline {}: {e}\n{}",
+                path.display(),
+                e.snippet(&raw)
+            )
+            .into()
+        })
+    }
+
+    /// Merge measurements into the named baseline, keeping any benchmarks that were not rerun
+    pub(crate) fn save(
+        name: &str,
+        measurements: &[Measurement],
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let path = Self::path(name);
+        let mut baseline = if path.exists() {
+            Self::load(name)?
+        } else {
+            Self::default()
+        };
+        for m in measurements {
+            baseline.0.insert(m.id.clone(), m.median);
+        }
+
+        std::fs::create_dir_all(Self::dir())?;
+        std::fs::write(&path, baseline.to_string())?;
+        Ok(path)
+    }
+
+    /// Parse a baseline saved as one `day<TAB>input<TAB>stage<TAB>nanoseconds` line per
+    /// benchmark
+    fn parse(raw: &str) -> Result<Self, ParseError> {
+        let mut baseline = Self::default();
+        for line in raw.lines() {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let [day, input, stage, nanos] = fields[..] else {
+                return Err(ParseError::at(
+                    raw,
+                    line,
+                    "`<day>\t<input>\t<stage>\t<nanoseconds>`",
+                ));
+            };
+            let id = BenchId {
+                day: parse_token(raw, day, "a day")?,
+                input: input.to_owned(),
+                stage: parse_token(raw, stage, "`parse`, `part1` or `part2`")?,
+            };
+            let nanos = parse_token(raw, nanos, "a number of nanoseconds")?;
+            baseline.0.insert(id, Duration::from_nanos(nanos));
+        }
+        Ok(baseline)
+    }
+
+    fn get(&self, id: &BenchId) -> Option<Duration> {
+        self.0.get(id).copied()
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (id, median) in &self.0 {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}",
+                id.day,
+                id.input,
+                id.stage,
+                median.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Format a duration with units scaled to its size, e.g. `12.345µs`
+fn format_duration(d: Duration) -> String {
+    format!("{d:.3?}")
+}
+
+/// Percentage change from `old` to `new`; positive when `new` is slower
+///
+/// A zero baseline can't be compared against, so gives `None`.
+fn change(old: Duration, new: Duration) -> Option<f64> {
+    (!old.is_zero()).then(|| (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0)
+}
+
+/// Render measurements as a table, comparing against a baseline if given
+///
+/// Returns the table along with the number of benchmarks that slowed down by more than
+/// `threshold` percent.
+pub(crate) fn report(
+    measurements: &[Measurement],
+    baseline: Option<&Baseline>,
+    threshold: f64,
+) -> (String, usize) {
+    let mut table = String::new();
+    let mut regressions = 0;
+
+    let _ = write!(
+        table,
+        "{:>3}  {:<10} {:<6} {:>14} {:>8}",
+        "day", "input", "stage", "median", "samples"
+    );
+    if baseline.is_some() {
+        let _ = write!(table, " {:>14} {:>8}", "baseline", "change");
+    }
+    table.push('\n');
+
+    for m in measurements {
+        let _ = write!(
+            table,
+            "{:>3}  {:<10} {:<6} {:>14} {:>8}",
+            m.id.day,
+            m.id.input,
+            m.id.stage.to_string(),
+            format_duration(m.median),
+            m.samples
+        );
+        match baseline.map(|b| b.get(&m.id)) {
+            Some(Some(old)) => match change(old, m.median) {
+                Some(change) => {
+                    let _ = write!(table, " {:>14} {:>+7.1}%", format_duration(old), change);
+                    if change > threshold {
+                        regressions += 1;
+                        table.push_str("  regressed");
+                    } else if change < -threshold {
+                        table.push_str("  improved");
+                    }
+                }
+                None => {
+                    let _ = write!(table, " {:>14} {:>8}", format_duration(old), "-");
+                }
+            },
+            Some(None) => {
+                let _ = write!(table, " {:>14} {:>8}", "-", "new");
+            }
+            None => {}
+        }
+        table.push('\n');
+    }
+
+    (table, regressions)
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::*;
+
+    fn measurement(day: u8, stage: Stage, millis: u64) -> Measurement {
+        Measurement {
+            id: BenchId {
+                day,
+                input: "sample".to_owned(),
+                stage,
+            },
+            median: Duration::from_millis(millis),
+            samples: 10,
+        }
+    }
+
+    #[test_case::test_case("2.5" => Ok(Duration::from_millis(2500)) ; "fractional")]
+    #[test_case::test_case("0" => Ok(Duration::ZERO) ; "zero")]
+    #[test_case::test_case("-1" => matches Err(_) ; "negative")]
+    #[test_case::test_case("NaN" => matches Err(_) ; "nan")]
+    #[test_case::test_case("1e30" => matches Err(_) ; "overflow")]
+    #[test_case::test_case("soon" => matches Err(_) ; "not a number")]
+    fn test_parse_measurement_time(s: &str) -> Result<Duration, String> {
+        parse_measurement_time(s)
+    }
+
+    #[test_case::test_case(vec![3, 1, 2] => 2 ; "odd")]
+    #[test_case::test_case(vec![4, 1, 3, 2] => 2 ; "even")]
+    #[test_case::test_case(vec![5] => 5 ; "single")]
+    fn test_median(millis: Vec<u64>) -> u64 {
+        median(millis.into_iter().map(Duration::from_millis).collect()).as_millis() as u64
+    }
+
+    #[test_case::test_case("parse" => Ok(Stage::Parse))]
+    #[test_case::test_case("part2" => Ok(Stage::Solve(Part::Two)))]
+    #[test_case::test_case("part3" => matches Err(_))]
+    #[test_case::test_case("solve" => matches Err(_))]
+    fn test_stage_round_trip(s: &str) -> Result<Stage, String> {
+        let stage = s.parse::<Stage>()?;
+        assert_eq!(stage.to_string(), s);
+        Ok(stage)
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        for m in [
+            measurement(6, Stage::Parse, 1),
+            measurement(6, Stage::Solve(Part::Two), 250),
+        ] {
+            baseline.0.insert(m.id, m.median);
+        }
+
+        let saved = baseline.to_string();
+        assert_eq!(
+            saved,
+            "6\tsample\tparse\t1000000\n6\tsample\tpart2\t250000000\n"
+        );
+        assert_eq!(Baseline::parse(&saved), Ok(baseline));
+    }
+
+    #[test]
+    fn test_baseline_parse_error() {
+        let err = Baseline::parse("6\tsample\tparse\t1000\n6\tsample\tpart3\t1000\n")
+            .expect_err("stage should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 10, "part3"));
+    }
+
+    #[test]
+    fn test_report_flags_regressions() {
+        let mut baseline = Baseline::default();
+        for m in [
+            measurement(6, Stage::Solve(Part::One), 100),
+            measurement(6, Stage::Solve(Part::Two), 100),
+        ] {
+            baseline.0.insert(m.id, m.median);
+        }
+
+        let (table, regressions) = report(
+            &[
+                measurement(6, Stage::Parse, 1),
+                measurement(6, Stage::Solve(Part::One), 105),
+                measurement(6, Stage::Solve(Part::Two), 150),
+            ],
+            Some(&baseline),
+            10.0,
+        );
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(regressions, 1);
+        assert!(lines[1].ends_with("new"));
+        assert!(lines[2].ends_with("+5.0%"));
+        assert!(lines[3].ends_with("+50.0%  regressed"));
+    }
+
+    #[test]
+    fn test_report_zero_baseline() {
+        let mut baseline = Baseline::default();
+        let m = measurement(6, Stage::Parse, 0);
+        baseline.0.insert(m.id, m.median);
+
+        let (table, regressions) =
+            report(&[measurement(6, Stage::Parse, 5)], Some(&baseline), 10.0);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(change(Duration::ZERO, Duration::from_millis(5)), None);
+        assert_eq!(regressions, 0);
+        assert!(lines[1].ends_with("0.000ns        -"), "{}", lines[1]);
+    }
+}
@@ -0,0 +1,134 @@
+//! Per-user cache of puzzle inputs and answer attempts, kept outside the workspace so that
+//! it survives checkouts and is shared between them.
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{input, YEAR};
+
+/// Cache directory laid out by year and day:
+///
+/// ```text
+/// 2024/day7/input.txt
+/// 2024/submissions.tsv
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub(crate) fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Cache in the given directory, or in the platform's per-user cache directory if omitted
+    pub(crate) fn locate(root: Option<PathBuf>) -> Result<Self, Box<dyn Error>> {
+        root.or_else(|| dirs::cache_dir().map(|dir| dir.join("aoc")))
+            .map(Self::new)
+            .ok_or_else(|| {
+                "could not find a cache directory for this user; pass --cache-dir or set AOC_CACHE_DIR"
+                    .into()
+            })
+    }
+
+    pub(crate) fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Location of a day's puzzle input
+    pub(crate) fn input_path(&self, day: u8) -> PathBuf {
+        input::cached_input_path(&self.root, day)
+    }
+
+    /// Location of the log of answer attempts for this year
+    pub(crate) fn submissions_path(&self) -> PathBuf {
+        self.root.join(YEAR.to_string()).join("submissions.tsv")
+    }
+
+    /// Save a day's puzzle input, returning where it was saved
+    ///
+    /// Saving the same input again is a no-op, but a different input is only replaced if
+    /// `force` is set.
+    pub(crate) fn store_input(
+        &self,
+        day: u8,
+        raw: &str,
+        force: bool,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        if raw.trim().is_empty() {
+            return Err(format!("refusing to save an empty input for day {day}").into());
+        }
+        let path = self.input_path(day);
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == raw => return Ok(path),
+            Ok(_) if !force => {
+                return Err(format!(
+                    "{} already holds a different input for day {day}; pass --force to replace it",
+                    path.display()
+                )
+                .into())
+            }
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("could not read {}: {e}", path.display()).into()),
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, raw).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+
+    #[test]
+    fn test_layout() {
+        let cache = Cache::new("cache".into());
+        assert_eq!(cache.input_path(7), Path::new("cache/2024/day7/input.txt"));
+        assert_eq!(
+            cache.submissions_path(),
+            Path::new("cache/2024/submissions.tsv")
+        );
+        assert_eq!(
+            Cache::locate(Some("elsewhere".into())).expect("cache dir was given"),
+            Cache::new("elsewhere".into())
+        );
+    }
+
+    #[test]
+    fn test_store_input() {
+        let root = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::new(root.clone());
+
+        let path = cache
+            .store_input(5, "1 2 3\n", false)
+            .expect("could not store input");
+        assert_eq!(fs::read_to_string(&path).expect("no input"), "1 2 3\n");
+        cache
+            .store_input(5, "1 2 3\n", false)
+            .expect("storing the same input again is fine");
+
+        let err = cache
+            .store_input(5, "4 5 6\n", false)
+            .expect_err("input should not be replaced");
+        assert!(err.to_string().contains("--force"));
+        assert_eq!(fs::read_to_string(&path).expect("no input"), "1 2 3\n");
+
+        cache
+            .store_input(5, "4 5 6\n", true)
+            .expect("could not replace input");
+        assert_eq!(fs::read_to_string(&path).expect("no input"), "4 5 6\n");
+
+        assert!(cache.store_input(6, "\n", false).is_err());
+        assert!(!cache.input_path(6).exists());
+
+        fs::remove_dir_all(root).expect("could not remove cache");
+    }
+}
@@ -0,0 +1,439 @@
+//! Fetching puzzle inputs from, and submitting answers to, the Advent of Code website.
+//!
+//! Requests go through a [`Transport`], so that everything above the raw HTTP exchange can
+//! be tested against a local fake of the site.
+use std::{
+    error::Error,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_common::{Part, YEAR};
+
+use crate::submissions::Outcome;
+
+/// Address of the Advent of Code website
+pub(crate) const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the runner to the site, as its maintainers ask of automated tools
+const USER_AGENT: &str = concat!(
+    "github.com/travipross/aoc2024 (aoc runner ",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+/// Status and body of a response from the site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Response {
+    pub(crate) status: u16,
+    pub(crate) body: String,
+}
+
+/// Sends authenticated requests to the site, given paths relative to its root
+pub(crate) trait Transport {
+    fn get(&self, path: &str) -> io::Result<Response>;
+
+    /// Post a URL-encoded form
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> io::Result<Response>;
+}
+
+/// Transport making real HTTP requests, authenticated by a session cookie
+pub(crate) struct HttpTransport {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpTransport {
+    pub(crate) fn new(base_url: &str, session: String) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> io::Result<Response> {
+        let request = self
+            .agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session));
+        let result = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let response = match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(e)) => return Err(io::Error::other(e.to_string())),
+        };
+        Ok(Response {
+            status: response.status(),
+            body: response.into_string()?,
+        })
+    }
+}
+
+impl Transport for HttpTransport {
+    fn get(&self, path: &str) -> io::Result<Response> {
+        self.send("GET", path, None)
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> io::Result<Response> {
+        self.send("POST", path, Some(form))
+    }
+}
+
+/// Default location of the session token, used when `AOC_SESSION` is not set
+pub(crate) fn session_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("aoc").join("session"))
+}
+
+/// Session token given directly, or else read from the file at `path`
+pub(crate) fn session_token(
+    token: Option<String>,
+    path: Option<&Path>,
+) -> Result<String, Box<dyn Error>> {
+    let missing = || {
+        let path = path.map_or("<config dir>/aoc/session".into(), |p| {
+            p.display().to_string()
+        });
+        format!("no session token; set AOC_SESSION or save the `session` cookie from adventofcode.com to {path}")
+    };
+    let token = match (token, path) {
+        (Some(token), _) => token,
+        (None, Some(path)) => match std::fs::read_to_string(path) {
+            Ok(token) => token,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Err(missing().into()),
+            Err(e) => return Err(format!("could not read {}: {e}", path.display()).into()),
+        },
+        (None, None) => return Err(missing().into()),
+    };
+
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(missing().into());
+    }
+    Ok(token.strip_prefix("session=").unwrap_or(token).to_owned())
+}
+
+/// Failure to fetch an input or submit an answer
+#[derive(Debug)]
+pub(crate) enum ClientError {
+    /// The request could not be made, or its response could not be read
+    Io(io::Error),
+    /// The site did not accept the session token
+    Unauthorized,
+    /// The day's puzzle has not been unlocked yet
+    Locked { day: u8 },
+    /// An answer was submitted before the wait after a wrong one had passed
+    TooSoon { wait: String },
+    /// The part is already solved, or its first part is not
+    WrongLevel { day: u8, part: Part },
+    /// Any other response the client does not understand
+    Unexpected { status: u16, message: String },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not reach Advent of Code: {err}"),
+            Self::Unauthorized => write!(
+                f,
+                "the session token was rejected; log in to adventofcode.com again and update it"
+            ),
+            Self::Locked { day } => write!(f, "day {day} of {YEAR} is not unlocked yet"),
+            Self::TooSoon { wait } => {
+                write!(
+                    f,
+                    "an answer was submitted too recently; {wait} left to wait"
+                )
+            }
+            Self::WrongLevel { day, part } => write!(
+                f,
+                "day {day} part {part} cannot be answered; is it already solved?"
+            ),
+            Self::Unexpected { status, message } => {
+                write!(
+                    f,
+                    "unexpected response from Advent of Code ({status}): {message}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Visible text of the `<article>` the site puts its messages in, or of the whole body if
+/// there is none
+fn article_text(body: &str) -> String {
+    let article = body
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Client for the parts of the site used while solving a day
+pub(crate) struct Client<T> {
+    transport: T,
+}
+
+impl<T: Transport> Client<T> {
+    pub(crate) fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// Body of a successful response, or the error a failed one amounts to
+    fn body(day: u8, response: io::Result<Response>) -> Result<String, ClientError> {
+        let response = response.map_err(ClientError::Io)?;
+        match response.status {
+            200 => Ok(response.body),
+            // the site answers a missing cookie with 400 and an invalid one with 500
+            400 | 500 => Err(ClientError::Unauthorized),
+            404 => Err(ClientError::Locked { day }),
+            status => Err(ClientError::Unexpected {
+                status,
+                message: article_text(&response.body),
+            }),
+        }
+    }
+
+    /// Download a day's puzzle input
+    pub(crate) fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        let path = format!("/{YEAR}/day/{day}/input");
+        Self::body(day, self.transport.get(&path))
+    }
+
+    /// Submit an answer to a part of a day, returning how it was received
+    pub(crate) fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome, ClientError> {
+        let path = format!("/{YEAR}/day/{day}/answer");
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let message = article_text(&Self::body(day, self.transport.post_form(&path, &form))?);
+
+        if message.starts_with("That's the right answer") {
+            Ok(Outcome::Correct)
+        } else if message.starts_with("That's not the right answer") {
+            Ok(if message.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if message.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            })
+        } else if message.starts_with("You gave an answer too recently") {
+            let wait = message
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map_or("some time", |(wait, _)| wait);
+            Err(ClientError::TooSoon {
+                wait: wait.to_owned(),
+            })
+        } else if message.starts_with("You don't seem to be solving the right level") {
+            Err(ClientError::WrongLevel { day, part })
+        } else {
+            Err(ClientError::Unexpected {
+                status: 200,
+                message,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use std::{sync::Arc, thread};
+
+    use super::*;
+
+    const SESSION: &str = "53a5e55";
+
+    /// A stand-in for the website on a local port, answering the way the real one does
+    /// for day 1, whose part 1 answer is 11
+    struct FakeServer {
+        server: Arc<tiny_http::Server>,
+        url: String,
+    }
+
+    impl FakeServer {
+        fn start() -> Self {
+            let server =
+                Arc::new(tiny_http::Server::http("127.0.0.1:0").expect("could not start server"));
+            let port = server
+                .server_addr()
+                .to_ip()
+                .expect("server should listen on an IP address")
+                .port();
+
+            let requests = Arc::clone(&server);
+            thread::spawn(move || {
+                for mut request in requests.incoming_requests() {
+                    let authorized = request.headers().iter().any(|h| {
+                        h.field.equiv("Cookie") && h.value.as_str() == format!("session={SESSION}")
+                    });
+                    let mut form = String::new();
+                    let _ = request.as_reader().read_to_string(&mut form);
+                    let (status, body) =
+                        Self::respond(request.method().as_str(), request.url(), authorized, &form);
+                    let _ = request
+                        .respond(tiny_http::Response::from_string(body).with_status_code(status));
+                }
+            });
+
+            Self {
+                server,
+                url: format!("http://127.0.0.1:{port}"),
+            }
+        }
+
+        fn respond(method: &str, url: &str, authorized: bool, form: &str) -> (u16, String) {
+            let article = |text: &str| {
+                format!("<html><main><article><p>{text} <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article></main></html>")
+            };
+            if !authorized {
+                return (
+                    400,
+                    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                        .to_owned(),
+                );
+            }
+            match (method, url) {
+                ("GET", "/2024/day/1/input") => (200, "3   4\n4   3\n".to_owned()),
+                ("POST", "/2024/day/1/answer") => {
+                    let text = match form {
+                        "level=1&answer=11" => "That's the right answer!  You are <span class=\"quiet\">one gold star</span> closer to finding the Chief Historian.",
+                        "level=1&answer=12" => "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.",
+                        "level=1&answer=10" => "That's not the right answer; your answer is too low.",
+                        "level=1&answer=abc" => "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                        "level=1&answer=13" => "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 36s left to wait.",
+                        _ => "You don't seem to be solving the right level.  Did you already complete it?",
+                    };
+                    (200, article(text))
+                }
+                _ => (404, "404 Not Found".to_owned()),
+            }
+        }
+
+        fn client(&self, session: &str) -> Client<HttpTransport> {
+            Client::new(HttpTransport::new(&self.url, session.to_owned()))
+        }
+    }
+
+    impl Drop for FakeServer {
+        fn drop(&mut self) {
+            self.server.unblock();
+        }
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let server = FakeServer::start();
+        let client = server.client(SESSION);
+        assert_eq!(
+            client.fetch_input(1).expect("could not fetch input"),
+            "3   4\n4   3\n"
+        );
+        assert!(matches!(
+            client.fetch_input(25),
+            Err(ClientError::Locked { day: 25 })
+        ));
+        assert!(matches!(
+            server.client("expired").fetch_input(1),
+            Err(ClientError::Unauthorized)
+        ));
+    }
+
+    #[test_case::test_case(Part::One, "11" => matches Ok(Outcome::Correct) ; "correct")]
+    #[test_case::test_case(Part::One, "12" => matches Ok(Outcome::TooHigh) ; "too high")]
+    #[test_case::test_case(Part::One, "10" => matches Ok(Outcome::TooLow) ; "too low")]
+    #[test_case::test_case(Part::One, "abc" => matches Ok(Outcome::Wrong) ; "wrong")]
+    #[test_case::test_case(Part::One, "13" => matches Err(ClientError::TooSoon { wait }) if wait == "36s" ; "too soon")]
+    #[test_case::test_case(Part::Two, "31" => matches Err(ClientError::WrongLevel { day: 1, part: Part::Two }) ; "wrong level")]
+    fn test_submit(part: Part, answer: &str) -> Result<Outcome, ClientError> {
+        FakeServer::start().client(SESSION).submit(1, part, answer)
+    }
+
+    /// Transport for a site that can't be reached at all
+    struct Unreachable;
+
+    impl Transport for Unreachable {
+        fn get(&self, _path: &str) -> io::Result<Response> {
+            Err(io::ErrorKind::ConnectionRefused.into())
+        }
+
+        fn post_form(&self, _path: &str, _form: &[(&str, &str)]) -> io::Result<Response> {
+            Err(io::ErrorKind::ConnectionRefused.into())
+        }
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let client = Client::new(Unreachable);
+        assert!(matches!(client.fetch_input(1), Err(ClientError::Io(_))));
+        assert!(matches!(
+            client.submit(1, Part::One, "42"),
+            Err(ClientError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_article_text() {
+        assert_eq!(
+            article_text("<main><article class=\"day-desc\"><p>That's <em>the</em>\n  right answer!</p></article></main>"),
+            "That's the right answer!"
+        );
+        assert_eq!(article_text("no article"), "no article");
+    }
+
+    #[test]
+    fn test_session_token() {
+        let dir = std::env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("could not create dir");
+        let path = dir.join("session");
+
+        let err = session_token(None, Some(&path)).expect_err("no token saved yet");
+        assert!(err.to_string().contains("AOC_SESSION"));
+
+        std::fs::write(&path, "session=53a5e55\n").expect("could not write token");
+        assert_eq!(
+            session_token(None, Some(&path)).expect("token is saved"),
+            SESSION
+        );
+        assert_eq!(
+            session_token(Some("f00d".to_owned()), Some(&path)).expect("token was given"),
+            "f00d"
+        );
+
+        std::fs::remove_dir_all(dir).expect("could not remove dir");
+    }
+}
@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{ParseError, Part, Solution};
+
+/// Answer to a single part of a puzzle, along with the time taken to compute it
+pub(crate) struct PartResult {
+    pub(crate) part: Part,
+    pub(crate) answer: String,
+    pub(crate) elapsed: Duration,
+}
+
+/// Answers to the requested parts of a day's puzzle, along with the time taken to parse the input
+pub(crate) struct DayResult {
+    pub(crate) parse_elapsed: Duration,
+    pub(crate) parts: Vec<PartResult>,
+}
+
+/// Type-erased entry point to a day's solution
+pub(crate) struct Day {
+    pub(crate) day: u8,
+    pub(crate) run: fn(&str, &[Part]) -> Result<DayResult, ParseError>,
+    pub(crate) explain: fn(&str) -> Result<Option<String>, ParseError>,
+}
+
+/// Parse raw input and solve each requested part, timing every step
+fn run<S: Solution>(raw: &str, parts: &[Part]) -> Result<DayResult, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(raw)?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = S::solve(&input, part).to_string();
+            PartResult {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayResult {
+        parse_elapsed,
+        parts,
+    })
+}
+
+/// Parse raw input and describe it beyond the answers, if the day has anything to add
+fn explain<S: Solution>(raw: &str) -> Result<Option<String>, ParseError> {
+    S::parse(raw).map(|input| S::explain(&input))
+}
+
+macro_rules! days {
+    ($($krate:ident::$solution:ident),* $(,)?) => {
+        /// Every day with a solution, in order
+        pub(crate) const DAYS: &[Day] = &[
+            $(Day {
+                day: <$krate::$solution as Solution>::DAY,
+                run: run::<$krate::$solution>,
+                explain: explain::<$krate::$solution>,
+            }),*
+        ];
+    };
+}
+
+days! {
+    day1::Day1,
+    day2::Day2,
+    day3::Day3,
+    day4::Day4,
+    day5::Day5,
+    day6::Day6,
+    day7::Day7,
+    day8::Day8,
+    day9::Day9,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+}
+
+/// Look up the solution for a given day
+pub(crate) fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod days_tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_unique_and_ordered() {
+        assert!(DAYS.is_sorted_by(|a, b| a.day < b.day));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(7).map(|d| d.day), Some(7));
+        assert!(find(26).is_none());
+    }
+}
@@ -0,0 +1,669 @@
+mod answers;
+mod bench;
+mod cache;
+mod client;
+mod days;
+mod scaffold;
+mod submissions;
+
+use std::{
+    error::Error,
+    io::Read,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, SystemTime},
+};
+
+use answers::{Answers, Verdict};
+use aoc_common::{
+    input::{self, InputError, InputSource},
+    Part,
+};
+use bench::{Baseline, BenchConfig};
+use cache::Cache;
+use clap::{Parser, Subcommand};
+use client::{Client, HttpTransport};
+use submissions::{Attempt, Outcome, SubmissionLog};
+
+/// Advent of Code 2024 solutions
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Per-user cache of puzzle inputs and answer attempts; defaults to `aoc` in the
+    /// platform's cache directory
+    #[arg(long, global = true, env = "AOC_CACHE_DIR")]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a day's puzzle, printing each answer and how long it took
+    Run {
+        /// Puzzle day (1-25)
+        #[arg(short, long)]
+        day: u8,
+
+        /// Only solve the given part (1 or 2); both parts are solved if omitted
+        #[arg(short, long)]
+        part: Option<Part>,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// Describe a day's puzzle input beyond its answers, for days that have more to say
+    Explain {
+        /// Puzzle day (1-25)
+        #[arg(short, long)]
+        day: u8,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// Time parsing and each part of every day against its samples and puzzle input
+    Bench {
+        /// Only benchmark the given day; every day is benchmarked if omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Only benchmark against the sample inputs
+        #[arg(long)]
+        sample_only: bool,
+
+        /// Seconds spent sampling each stage
+        #[arg(long, default_value = "1", value_name = "SECONDS", value_parser = bench::parse_measurement_time)]
+        measurement_time: Duration,
+
+        /// Save the results as a named baseline for later comparison
+        #[arg(long, value_name = "NAME")]
+        save_baseline: Option<String>,
+
+        /// Compare the results against a previously saved baseline
+        #[arg(long, value_name = "NAME")]
+        baseline: Option<String>,
+
+        /// Slowdown against the baseline, in percent, that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// Solve every day against its puzzle input and check the answers against those recorded
+    Verify {
+        /// Only verify the given day; every day is verified if omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// File of known-correct answers; defaults to `answers.toml` at the workspace root
+        #[arg(long, env = "AOC_ANSWERS")]
+        answers: Option<PathBuf>,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// Generate a crate for a new day, register it with the runner and fetch its input
+    New {
+        /// Puzzle day (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Don't fetch the day's puzzle input
+        #[arg(long)]
+        no_fetch: bool,
+
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+
+    /// Manage the puzzle inputs in the cache
+    Input {
+        #[command(subcommand)]
+        command: InputCommand,
+    },
+
+    /// Record the outcome of submitting an answer in the submission log
+    Record {
+        /// Puzzle day (1-25)
+        #[arg(short, long)]
+        day: u8,
+
+        /// Part the answer is for (1 or 2)
+        #[arg(short, long)]
+        part: Part,
+
+        /// How the answer was received: correct, wrong, too-high or too-low
+        outcome: Outcome,
+
+        /// Answer that was submitted; the part is solved to get it if omitted
+        #[arg(short, long)]
+        answer: Option<String>,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// Submit an answer to Advent of Code and record how it was received
+    Submit {
+        /// Puzzle day (1-25)
+        #[arg(short, long)]
+        day: u8,
+
+        /// Part to answer (1 or 2)
+        #[arg(short, long)]
+        part: Part,
+
+        /// Answer to submit; the part is solved to get it if omitted
+        #[arg(short, long)]
+        answer: Option<String>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+
+    /// Show the answers attempted so far
+    Log {
+        /// Only show attempts for the given day
+        #[arg(short, long)]
+        day: Option<u8>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum InputCommand {
+    /// Save a day's puzzle input to the cache
+    Add {
+        /// Puzzle day (1-25)
+        #[arg(short, long)]
+        day: u8,
+
+        /// File to read the input from, or `-` for stdin (the default)
+        file: Option<PathBuf>,
+
+        /// Replace a different input already saved for the day
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Download a day's puzzle input from Advent of Code into the cache
+    Fetch {
+        /// Puzzle day (1-25)
+        #[arg(short, long)]
+        day: u8,
+
+        /// Replace a different input already saved for the day
+        #[arg(long)]
+        force: bool,
+
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct InputArgs {
+    /// Puzzle input file, or `-` to read from stdin
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Directory of puzzle inputs named `day{N}.txt`; defaults to the inputs in the cache
+    #[arg(long, env = "AOC_INPUTS_DIR")]
+    inputs_dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    fn source(self, cache_dir: Option<PathBuf>) -> Result<InputSource, Box<dyn Error>> {
+        Ok(match (self.input, self.inputs_dir) {
+            (Some(path), _) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(path),
+            (None, Some(dir)) => InputSource::Dir(dir),
+            (None, None) => InputSource::Cache(Cache::locate(cache_dir)?.root().to_path_buf()),
+        })
+    }
+}
+
+#[derive(Debug, clap::Args)]
+struct RemoteArgs {
+    /// Session cookie from adventofcode.com; read from `aoc/session` in the platform's
+    /// config directory if omitted
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// Address of the Advent of Code website
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::BASE_URL, hide = true)]
+    base_url: String,
+}
+
+impl RemoteArgs {
+    fn client(self) -> Result<Client<HttpTransport>, Box<dyn Error>> {
+        let session = client::session_token(self.session, client::session_path().as_deref())?;
+        Ok(Client::new(HttpTransport::new(&self.base_url, session)))
+    }
+}
+
+/// Root of the workspace the runner was built in
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("aoc should live in the crates directory")
+        .to_path_buf()
+}
+
+fn format_duration(d: Duration) -> String {
+    format!("{:.3}ms", d.as_secs_f64() * 1000.0)
+}
+
+/// Solve the given parts of a day against its input
+fn solve(day: u8, parts: &[Part], input: &InputSource) -> Result<days::DayResult, Box<dyn Error>> {
+    let solution = days::find(day).ok_or(format!("no solution for day {day}"))?;
+    let raw = input::load(day, input)?;
+    (solution.run)(&raw, parts).map_err(|e| {
+        format!(
+            "could not parse input for day {day}: {e}\n{}",
+            e.snippet(&raw)
+        )
+        .into()
+    })
+}
+
+fn run(day: u8, part: Option<Part>, input: InputSource) -> Result<(), Box<dyn Error>> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let result = solve(day, &parts, &input)?;
+
+    println!(
+        "Day {day} (parsed in {})",
+        format_duration(result.parse_elapsed)
+    );
+    for p in result.parts {
+        println!(
+            "Part {}: {} ({})",
+            p.part,
+            p.answer,
+            format_duration(p.elapsed)
+        );
+    }
+    Ok(())
+}
+
+fn explain(day: u8, input: InputSource) -> Result<(), Box<dyn Error>> {
+    let solution = days::find(day).ok_or(format!("no solution for day {day}"))?;
+    let raw = input::load(day, &input)?;
+    let explanation = (solution.explain)(&raw).map_err(|e| {
+        format!(
+            "could not parse input for day {day}: {e}\n{}",
+            e.snippet(&raw)
+        )
+    })?;
+    print!(
+        "{}",
+        explanation.ok_or(format!("day {day} has nothing to explain"))?
+    );
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn bench(
+    day: Option<u8>,
+    sample_only: bool,
+    measurement_time: Duration,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+    input: InputArgs,
+    cache_dir: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let days = match day {
+        Some(day) => vec![days::find(day).ok_or(format!("no solution for day {day}"))?],
+        None if input.input.is_some() => {
+            return Err("--input can only be used when benchmarking a single --day".into())
+        }
+        None => days::DAYS.iter().collect(),
+    };
+    // load the baseline up front so a bad name fails before any time is spent measuring
+    let baseline = baseline.map(|name| Baseline::load(&name)).transpose()?;
+    let source = input.source(cache_dir)?;
+    let config = BenchConfig {
+        measurement_time,
+        ..BenchConfig::default()
+    };
+
+    let mut measurements = vec![];
+    for solution in days {
+        let day = solution.day;
+        let mut inputs = vec![];
+        for path in input::sample_paths(day)? {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            inputs.push((name, std::fs::read_to_string(path)?));
+        }
+        if !sample_only {
+            match input::load(day, &source) {
+                Ok(raw) => inputs.push(("input".to_owned(), raw)),
+                Err(InputError::NotFound { .. }) => {
+                    eprintln!("Day {day}: no puzzle input found, only benchmarking samples")
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        for (name, raw) in inputs {
+            eprintln!("Day {day}: benchmarking {name}...");
+            measurements.extend(bench::measure(solution, &name, &raw, &config).map_err(|e| {
+                format!(
+                    "could not parse {name} for day {day}: {e}\n{}",
+                    e.snippet(&raw)
+                )
+            })?);
+        }
+    }
+
+    let (table, regressions) = bench::report(&measurements, baseline.as_ref(), threshold);
+    print!("{table}");
+
+    if let Some(name) = save_baseline {
+        let path = Baseline::save(&name, &measurements)?;
+        println!("Saved baseline {name} to {}", path.display());
+    }
+    if regressions > 0 {
+        return Err(
+            format!("{regressions} benchmark(s) regressed by more than {threshold}%").into(),
+        );
+    }
+    Ok(())
+}
+
+fn verify(
+    day: Option<u8>,
+    answers: Option<PathBuf>,
+    input: InputArgs,
+    cache_dir: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let days = match day {
+        Some(day) => vec![days::find(day).ok_or(format!("no solution for day {day}"))?],
+        None if input.input.is_some() => {
+            return Err("--input can only be used when verifying a single --day".into())
+        }
+        None => days::DAYS.iter().collect(),
+    };
+    let answers = Answers::load(&answers.unwrap_or_else(Answers::default_path))?;
+    let source = input.source(cache_dir)?;
+
+    let (mut passed, mut failed, mut unknown, mut skipped) = (0, 0, 0, 0);
+    for solution in days {
+        let day = solution.day;
+        let raw = match input::load(day, &source) {
+            Ok(raw) => raw,
+            Err(InputError::NotFound { .. }) => {
+                println!("Day {day}: skipped, no puzzle input");
+                skipped += 1;
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let result = (solution.run)(&raw, &Part::ALL).map_err(|e| {
+            format!(
+                "could not parse input for day {day}: {e}\n{}",
+                e.snippet(&raw)
+            )
+        })?;
+
+        for p in result.parts {
+            let verdict = answers.check(day, p.part, &p.answer);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Unknown => unknown += 1,
+            }
+            println!("Day {day} part {}: {} {verdict}", p.part, p.answer);
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {unknown} unknown, {skipped} day(s) skipped");
+    if failed > 0 {
+        return Err(format!("{failed} answer(s) differ from those recorded").into());
+    }
+    Ok(())
+}
+
+fn new(
+    day: u8,
+    no_fetch: bool,
+    remote: RemoteArgs,
+    cache_dir: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let root = workspace_root();
+    for path in scaffold::new_day(&root, day)? {
+        println!("{}", path.strip_prefix(&root).unwrap_or(&path).display());
+    }
+    println!("Created day{day}; rebuild the runner to solve it with `aoc run --day {day}`");
+
+    if !no_fetch {
+        // the crate is already in place, so a failed fetch is only worth a warning
+        if let Err(e) = input_fetch(day, false, remote, cache_dir) {
+            eprintln!("warning: could not fetch the input for day {day}: {e}");
+            eprintln!("Fetch it later with `aoc input fetch --day {day}`, or save it with `aoc input add --day {day} <file>`");
+        }
+    }
+    Ok(())
+}
+
+fn input_add(
+    day: u8,
+    file: Option<PathBuf>,
+    force: bool,
+    cache: &Cache,
+) -> Result<(), Box<dyn Error>> {
+    let raw = match file {
+        Some(path) if path.as_os_str() != "-" => std::fs::read_to_string(&path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?,
+        _ => {
+            let mut raw = String::new();
+            std::io::stdin().read_to_string(&mut raw)?;
+            raw
+        }
+    };
+    let path = cache.store_input(day, &raw, force)?;
+    println!("Saved input for day {day} to {}", path.display());
+    Ok(())
+}
+
+fn input_fetch(
+    day: u8,
+    force: bool,
+    remote: RemoteArgs,
+    cache_dir: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let cache = Cache::locate(cache_dir)?;
+    // inputs never change, so there is no need to download one that is already saved
+    if !force && cache.input_path(day).exists() {
+        println!(
+            "Input for day {day} is already saved at {}",
+            cache.input_path(day).display()
+        );
+        return Ok(());
+    }
+    let raw = remote.client()?.fetch_input(day)?;
+    let path = cache.store_input(day, &raw, force)?;
+    println!("Saved input for day {day} to {}", path.display());
+    Ok(())
+}
+
+/// The given answer, or else the answer to the part solved against its input
+fn answer_or_solve(
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    input: InputArgs,
+    cache: &Cache,
+) -> Result<String, Box<dyn Error>> {
+    match answer {
+        Some(answer) => Ok(answer),
+        None => {
+            let source = input.source(Some(cache.root().to_path_buf()))?;
+            Ok(solve(day, &[part], &source)?.parts.remove(0).answer)
+        }
+    }
+}
+
+fn record(
+    day: u8,
+    part: Part,
+    outcome: Outcome,
+    answer: Option<String>,
+    input: InputArgs,
+    cache_dir: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let cache = Cache::locate(cache_dir)?;
+    let answer = answer_or_solve(day, part, answer, input, &cache)?;
+
+    let path = cache.submissions_path();
+    let mut log = SubmissionLog::load(&path)?;
+    log.record(
+        &path,
+        Attempt {
+            time: SystemTime::now(),
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+        },
+    )?;
+    println!("Recorded day {day} part {part}: {answer} ({outcome})");
+    Ok(())
+}
+
+fn submit(
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    input: InputArgs,
+    remote: RemoteArgs,
+    cache_dir: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let cache = Cache::locate(cache_dir)?;
+    let answer = answer_or_solve(day, part, answer, input, &cache)?;
+
+    let path = cache.submissions_path();
+    let mut log = SubmissionLog::load(&path)?;
+    if let Some(correct) = log.correct(day, part) {
+        return Err(format!(
+            "day {day} part {part} is already solved with {}",
+            correct.answer
+        )
+        .into());
+    }
+    log.check_untried(day, part, &answer)?;
+
+    let outcome = remote.client()?.submit(day, part, &answer)?;
+    log.record(
+        &path,
+        Attempt {
+            time: SystemTime::now(),
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+        },
+    )?;
+    println!("Day {day} part {part}: {answer} is {outcome}");
+    Ok(())
+}
+
+fn log(day: Option<u8>, cache: &Cache) -> Result<(), Box<dyn Error>> {
+    let log = SubmissionLog::load(&cache.submissions_path())?;
+    for attempt in log.attempts(day) {
+        println!(
+            "{}  day {:>2} part {}  {:<8}  {}",
+            humantime::format_rfc3339_seconds(attempt.time),
+            attempt.day,
+            attempt.part,
+            attempt.outcome.to_string(),
+            attempt.answer
+        );
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let Cli { command, cache_dir } = Cli::parse();
+    let result = match command {
+        Command::Run { day, part, input } => input
+            .source(cache_dir)
+            .and_then(|source| run(day, part, source)),
+        Command::Explain { day, input } => input
+            .source(cache_dir)
+            .and_then(|source| explain(day, source)),
+        Command::Bench {
+            day,
+            sample_only,
+            measurement_time,
+            save_baseline,
+            baseline,
+            threshold,
+            input,
+        } => bench(
+            day,
+            sample_only,
+            measurement_time,
+            save_baseline,
+            baseline,
+            threshold,
+            input,
+            cache_dir,
+        ),
+        Command::Verify {
+            day,
+            answers,
+            input,
+        } => verify(day, answers, input, cache_dir),
+        Command::New {
+            day,
+            no_fetch,
+            remote,
+        } => new(day, no_fetch, remote, cache_dir),
+        Command::Input {
+            command: InputCommand::Add { day, file, force },
+        } => Cache::locate(cache_dir).and_then(|cache| input_add(day, file, force, &cache)),
+        Command::Input {
+            command: InputCommand::Fetch { day, force, remote },
+        } => input_fetch(day, force, remote, cache_dir),
+        Command::Record {
+            day,
+            part,
+            outcome,
+            answer,
+            input,
+        } => record(day, part, outcome, answer, input, cache_dir),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+            remote,
+        } => submit(day, part, answer, input, remote, cache_dir),
+        Command::Log { day } => Cache::locate(cache_dir).and_then(|cache| log(day, &cache)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Scaffolding of new day crates from the templates in `crates/aoc/templates`.
+use std::{
+    error::Error,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs");
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/Cargo.toml.in");
+const BUILD_TEMPLATE: &str = include_str!("../templates/build.rs");
+const EXPECTED_TEMPLATE: &str = "\
+# answers the sample should produce, checked by the generated sample tests
+# part1 = <answer>
+# part2 = <answer>
+";
+
+/// Fill in a template's placeholders for the given day
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("DayXYZ", &format!("Day{day}"))
+        .replace("dayXYZ", &format!("day{day}"))
+        .replace("XYZ", &day.to_string())
+}
+
+/// Insert `entry` as a new line among the lines that `day_of` recognises as belonging to a
+/// day, keeping those lines in day order
+fn insert_in_day_order(
+    text: &str,
+    day: u8,
+    entry: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| day_of(line).map(|d| (idx, d)))
+        .collect::<Vec<_>>();
+
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(format!("day {day} is already registered"));
+    }
+    let idx = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(idx, _)) => idx,
+        None => {
+            days.last()
+                .ok_or("no existing days to register alongside")?
+                .0
+                + 1
+        }
+    };
+    lines.insert(idx, entry);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+/// Register a day as a dependency in the runner's manifest
+fn register_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    let entry = format!("day{day} = {{ path = \"../day{day}\" }}");
+    insert_in_day_order(manifest, day, &entry, |line| {
+        line.split_once(" = ")?.0.strip_prefix("day")?.parse().ok()
+    })
+}
+
+/// Register a day's solution in the `days!` list in `days.rs`
+fn register_solution(days_rs: &str, day: u8) -> Result<String, String> {
+    let entry = format!("    day{day}::Day{day},");
+    insert_in_day_order(days_rs, day, &entry, |line| {
+        line.trim()
+            .strip_prefix("day")?
+            .split_once("::")?
+            .0
+            .parse()
+            .ok()
+    })
+}
+
+/// Write a new file, failing rather than overwriting one that already exists
+fn create_file(path: &Path, contents: &str) -> Result<(), Box<dyn Error>> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| format!("could not create {}: {e}", path.display()).into())
+}
+
+/// Generate a crate for a new day in the workspace at `root`, and register it with the runner
+///
+/// Nothing is written if the day's crate already exists or the day is already registered.
+/// Returns the paths of every file created or modified.
+pub(crate) fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let crate_dir = root.join("crates").join(format!("day{day}"));
+    if crate_dir.exists() {
+        return Err(format!(
+            "{} already exists; refusing to overwrite it",
+            crate_dir.display()
+        )
+        .into());
+    }
+
+    let runner_dir = root.join("crates").join("aoc");
+    let manifest_path = runner_dir.join("Cargo.toml");
+    let days_path = runner_dir.join("src").join("days.rs");
+    let registration = |path: &Path, register: fn(&str, u8) -> Result<String, String>| {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        register(&text, day).map_err(|e| format!("could not update {}: {e}", path.display()))
+    };
+    let manifest = registration(&manifest_path, register_dependency)?;
+    let days_rs = registration(&days_path, register_solution)?;
+
+    fs::create_dir_all(crate_dir.join("src"))?;
+    fs::create_dir_all(crate_dir.join("samples"))?;
+    let files = [
+        (crate_dir.join("Cargo.toml"), render(MANIFEST_TEMPLATE, day)),
+        (
+            crate_dir.join("src").join("lib.rs"),
+            render(LIB_TEMPLATE, day),
+        ),
+        (crate_dir.join("build.rs"), BUILD_TEMPLATE.to_owned()),
+        (crate_dir.join("samples").join("sample.txt"), String::new()),
+        (
+            crate_dir.join("samples").join("sample.expected"),
+            EXPECTED_TEMPLATE.to_owned(),
+        ),
+    ];
+    let mut touched = vec![];
+    for (path, contents) in files {
+        create_file(&path, &contents)?;
+        touched.push(path);
+    }
+
+    fs::write(&manifest_path, manifest)?;
+    fs::write(&days_path, days_rs)?;
+    touched.extend([manifest_path, days_path]);
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod scaffold_tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+aoc-common = { path = "../aoc-common" }
+day1 = { path = "../day1" }
+day7 = { path = "../day7" }
+day12 = { path = "../day12" }
+"#;
+
+    const DAYS_RS: &str = r#"days! {
+    day1::Day1,
+    day7::Day7,
+    day12::Day12,
+}
+"#;
+
+    /// A throwaway workspace containing copies of the runner's registry files
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-new-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let runner_src = root.join("crates").join("aoc").join("src");
+        fs::create_dir_all(&runner_src).expect("could not create workspace");
+        fs::write(root.join("crates").join("aoc").join("Cargo.toml"), MANIFEST)
+            .expect("could not write manifest");
+        fs::write(runner_src.join("days.rs"), DAYS_RS).expect("could not write days.rs");
+        root
+    }
+
+    #[test]
+    fn test_render() {
+        let lib = render(LIB_TEMPLATE, 13);
+        assert!(lib.contains("pub struct Day13;"));
+        assert!(lib.contains("const DAY: u8 = 13;"));
+        assert!(lib.contains("mod day13_tests"));
+        assert!(!lib.contains("XYZ"));
+
+        assert!(render(MANIFEST_TEMPLATE, 13).contains("name = \"day13\""));
+    }
+
+    #[test_case::test_case(13, "day12 = { path = \"../day12\" }" ; "after the last day")]
+    #[test_case::test_case(5, "day1 = { path = \"../day1\" }" ; "between days")]
+    fn test_register_dependency(day: u8, previous: &str) {
+        let manifest = register_dependency(MANIFEST, day).expect("could not register day");
+        let entry = format!("day{day} = {{ path = \"../day{day}\" }}");
+        let expected = format!("{previous}\n{entry}\n");
+        assert!(manifest.contains(&expected), "{manifest}");
+    }
+
+    #[test]
+    fn test_register_solution() {
+        let days_rs = register_solution(DAYS_RS, 13).expect("could not register day");
+        assert!(days_rs.contains("    day12::Day12,\n    day13::Day13,\n}"));
+
+        let err = register_solution(&days_rs, 13).expect_err("day is already registered");
+        assert_eq!(err, "day 13 is already registered");
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = workspace("fresh");
+        let touched = new_day(&root, 13).expect("could not scaffold day");
+        assert_eq!(touched.len(), 7);
+
+        let crate_dir = root.join("crates").join("day13");
+        let lib = fs::read_to_string(crate_dir.join("src").join("lib.rs")).expect("no lib.rs");
+        assert!(lib.contains("impl Solution for Day13"));
+        assert!(crate_dir.join("samples").join("sample.txt").exists());
+        let expected = fs::read_to_string(crate_dir.join("samples").join("sample.expected"))
+            .expect("no sample.expected");
+        assert_eq!(
+            aoc_common::samples::Expected::parse(&expected),
+            Ok(Default::default())
+        );
+        assert!(!crate_dir.join("input.txt").exists());
+
+        let days_rs =
+            fs::read_to_string(root.join("crates").join("aoc").join("src").join("days.rs"))
+                .expect("no days.rs");
+        assert!(days_rs.contains("day13::Day13,"));
+
+        fs::remove_dir_all(root).expect("could not remove workspace");
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_clobber() {
+        let root = workspace("clobber");
+        let crate_dir = root.join("crates").join("day5");
+        fs::create_dir_all(&crate_dir).expect("could not create day");
+        fs::write(crate_dir.join("sample.txt"), "precious").expect("could not write sample");
+
+        let err = new_day(&root, 5).expect_err("day 5 already exists");
+        assert!(err.to_string().contains("refusing to overwrite"));
+        assert_eq!(
+            fs::read_to_string(crate_dir.join("sample.txt")).expect("sample is gone"),
+            "precious"
+        );
+
+        // registered but without a crate: nothing should be written
+        let err = new_day(&root, 7).expect_err("day 7 is already registered");
+        assert!(err.to_string().contains("already registered"));
+        assert!(!root.join("crates").join("day7").exists());
+
+        fs::remove_dir_all(root).expect("could not remove workspace");
+    }
+}
@@ -0,0 +1,290 @@
+//! Log of every answer attempted for each part, so that answers already known to be wrong
+//! are never tried twice.
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::SystemTime,
+};
+
+use aoc_common::{parse::parse_token, ParseError, Part};
+
+/// Response to submitting an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    Correct,
+    /// Wrong, without a hint as to which way
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooHigh => write!(f, "too-high"),
+            Self::TooLow => write!(f, "too-low"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "wrong" => Ok(Self::Wrong),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            _ => Err(format!(
+                "invalid outcome: {s}; expected correct, wrong, too-high or too-low"
+            )),
+        }
+    }
+}
+
+/// A single answer attempted for a part of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Attempt {
+    pub(crate) time: SystemTime,
+    pub(crate) day: u8,
+    pub(crate) part: Part,
+    pub(crate) answer: String,
+    pub(crate) outcome: Outcome,
+}
+
+impl Attempt {
+    /// Whether this attempt shows that `answer` to the same part is wrong too
+    ///
+    /// Besides the same wrong answer, a numeric answer is ruled out by one at or below it
+    /// that was too high, or one at or above it that was too low.
+    fn rules_out(&self, answer: &str) -> bool {
+        let numbers = self
+            .answer
+            .parse::<i128>()
+            .ok()
+            .zip(answer.parse::<i128>().ok());
+        match (self.outcome, numbers) {
+            (Outcome::Correct, _) => false,
+            (_, _) if self.answer == answer => true,
+            (Outcome::TooHigh, Some((known, new))) => new >= known,
+            (Outcome::TooLow, Some((known, new))) => new <= known,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            humantime::format_rfc3339_seconds(self.time),
+            self.day,
+            self.part,
+            self.outcome,
+            self.answer
+        )
+    }
+}
+
+/// Every answer attempted so far, stored as one
+/// `time<TAB>day<TAB>part<TAB>outcome<TAB>answer` line per attempt, e.g.
+/// `2024-12-07T05:03:12Z<TAB>7<TAB>1<TAB>too-low<TAB>3200`
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct SubmissionLog(Vec<Attempt>);
+
+impl SubmissionLog {
+    /// Load the log from a file; a missing file simply has no attempts recorded
+    pub(crate) fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(raw) => Self::parse(&raw).map_err(|e| {
+                format!(
+                    "could not parse submission log {}: {e}\n{}",
+                    path.display(),
+                    e.snippet(&raw)
+                )
+                .into()
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read submission log {}: {e}", path.display()).into()),
+        }
+    }
+
+    fn parse(raw: &str) -> Result<Self, ParseError> {
+        let mut log = Self::default();
+        for line in raw.lines() {
+            let fields = line.splitn(5, '\t').collect::<Vec<_>>();
+            let [time, day, part, outcome, answer] = fields[..] else {
+                return Err(ParseError::at(
+                    raw,
+                    line,
+                    "`<time>\t<day>\t<part>\t<outcome>\t<answer>`",
+                ));
+            };
+            log.0.push(Attempt {
+                time: humantime::parse_rfc3339(time)
+                    .map_err(|_| ParseError::at(raw, time, "an RFC 3339 timestamp"))?,
+                day: parse_token(raw, day, "a day")?,
+                part: parse_token(raw, part, "1 or 2")?,
+                outcome: parse_token(raw, outcome, "correct, wrong, too-high or too-low")?,
+                answer: answer.to_owned(),
+            });
+        }
+        Ok(log)
+    }
+
+    /// Attempts in the order they were made, optionally only those for one day
+    pub(crate) fn attempts(&self, day: Option<u8>) -> impl Iterator<Item = &Attempt> {
+        self.0
+            .iter()
+            .filter(move |attempt| day.is_none_or(|day| attempt.day == day))
+    }
+
+    /// Earliest attempt showing that an answer to a part is wrong, if any
+    pub(crate) fn known_wrong(&self, day: u8, part: Part, answer: &str) -> Option<&Attempt> {
+        self.attempts(Some(day))
+            .find(|attempt| attempt.part == part && attempt.rules_out(answer))
+    }
+
+    /// Fail if an answer to a part is already known to be wrong, explaining how
+    pub(crate) fn check_untried(&self, day: u8, part: Part, answer: &str) -> Result<(), String> {
+        match self.known_wrong(day, part, answer) {
+            Some(earlier) => Err(format!(
+                "{answer} is already known to be wrong for day {day} part {part}: {} was {} at {}",
+                earlier.answer,
+                earlier.outcome,
+                humantime::format_rfc3339_seconds(earlier.time)
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Correct answer to a part, if one has been recorded
+    pub(crate) fn correct(&self, day: u8, part: Part) -> Option<&Attempt> {
+        self.attempts(Some(day))
+            .find(|attempt| attempt.part == part && attempt.outcome == Outcome::Correct)
+    }
+
+    /// Add an attempt to the log, appending it to the file at `path`
+    ///
+    /// Answers already known to be wrong are refused rather than recorded again.
+    pub(crate) fn record(&mut self, path: &Path, attempt: Attempt) -> Result<(), Box<dyn Error>> {
+        self.check_untried(attempt.day, attempt.part, &attempt.answer)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{attempt}"))
+            .map_err(|e| format!("could not write submission log {}: {e}", path.display()))?;
+        self.0.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod submissions_tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    static LOG: &str = "\
+2024-12-07T05:03:12Z\t7\t1\ttoo-low\t3200
+2024-12-07T05:04:02Z\t7\t1\ttoo-high\t4000
+2024-12-07T05:04:40Z\t7\t1\tcorrect\t3749
+2024-12-07T05:20:00Z\t7\t2\twrong\tabc
+";
+
+    fn attempt(day: u8, part: Part, answer: &str, outcome: Outcome) -> Attempt {
+        Attempt {
+            time: SystemTime::UNIX_EPOCH + Duration::from_secs(1_733_547_792),
+            day,
+            part,
+            answer: answer.to_owned(),
+            outcome,
+        }
+    }
+
+    #[test_case::test_case("too-high" => Ok(Outcome::TooHigh))]
+    #[test_case::test_case("correct" => Ok(Outcome::Correct))]
+    #[test_case::test_case("high" => matches Err(_))]
+    fn test_outcome_round_trip(s: &str) -> Result<Outcome, String> {
+        let outcome = s.parse::<Outcome>()?;
+        assert_eq!(outcome.to_string(), s);
+        Ok(outcome)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let log = SubmissionLog::parse(LOG).expect("could not parse log");
+        assert_eq!(log.attempts(None).count(), 4);
+        assert_eq!(log.attempts(Some(8)).count(), 0);
+        assert_eq!(
+            log.attempts(None)
+                .map(|a| format!("{a}\n"))
+                .collect::<String>(),
+            LOG
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = SubmissionLog::parse("2024-12-07T05:03:12Z\t7\t3\ttoo-low\t3200\n")
+            .expect_err("part should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 24, "3"));
+
+        let err = SubmissionLog::parse("yesterday\t7\t1\ttoo-low\t3200\n")
+            .expect_err("time should be invalid");
+        assert_eq!(err.token, "yesterday");
+    }
+
+    #[test_case::test_case(Part::One, "3200" => Some("3200".to_owned()) ; "same wrong answer")]
+    #[test_case::test_case(Part::One, "3100" => Some("3200".to_owned()) ; "below too low")]
+    #[test_case::test_case(Part::One, "4100" => Some("4000".to_owned()) ; "above too high")]
+    #[test_case::test_case(Part::One, "3500" => None ; "in range")]
+    #[test_case::test_case(Part::One, "3749" => None ; "correct")]
+    #[test_case::test_case(Part::Two, "abc" => Some("abc".to_owned()) ; "non numeric")]
+    #[test_case::test_case(Part::Two, "3200" => None ; "other part")]
+    fn test_known_wrong(part: Part, answer: &str) -> Option<String> {
+        let log = SubmissionLog::parse(LOG).expect("could not parse log");
+        log.known_wrong(7, part, answer)
+            .map(|attempt| attempt.answer.clone())
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = std::env::temp_dir().join(format!("aoc-submissions-{}", std::process::id()));
+        let path = dir.join("2024").join("submissions.tsv");
+        let mut log = SubmissionLog::load(&path).expect("missing log is empty");
+
+        log.record(&path, attempt(7, Part::One, "3200", Outcome::TooLow))
+            .expect("could not record attempt");
+        log.record(&path, attempt(7, Part::One, "3749", Outcome::Correct))
+            .expect("could not record attempt");
+        let err = log
+            .record(&path, attempt(7, Part::One, "3000", Outcome::Wrong))
+            .expect_err("answer is known to be too low");
+        assert!(err.to_string().contains("3200 was too-low"));
+
+        assert_eq!(
+            log.correct(7, Part::One).map(|a| a.answer.as_str()),
+            Some("3749")
+        );
+        assert_eq!(log.correct(7, Part::Two), None);
+
+        assert_eq!(SubmissionLog::load(&path).expect("could not load log"), log);
+        assert_eq!(log.attempts(None).count(), 2);
+
+        fs::remove_dir_all(dir).expect("could not remove dir");
+    }
+}
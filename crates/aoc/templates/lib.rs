@@ -0,0 +1,50 @@
+use aoc_common::{ParseError, Solution};
+
+pub type Input = ();
+
+fn parse_input(raw: &str) -> Result<Input, ParseError> {
+    todo!()
+}
+
+fn part1(input: &Input) -> usize {
+    todo!()
+}
+
+fn part2(input: &Input) -> usize {
+    todo!()
+}
+
+pub struct DayXYZ;
+
+impl Solution for DayXYZ {
+    const DAY: u8 = XYZ;
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+aoc_common::sample_tests!(DayXYZ);
+
+#[cfg(test)]
+mod dayXYZ_tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let input = parse_input(SAMPLE).expect("could not parse sample");
+        todo!()
+    }
+}
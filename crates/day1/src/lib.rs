@@ -0,0 +1,241 @@
+use std::{collections::HashMap, fmt::Write, iter::zip};
+
+use aoc_common::{parse::parse_token, ParseError, Solution};
+
+/// The two lists of location IDs written side by side in the puzzle input
+///
+/// Both lists are kept sorted, so that the `n`th smallest IDs of each are paired up, and the
+/// occurrences of each ID in the right list are counted up front for similarity scoring.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationLists {
+    left: Vec<u32>,
+    right: Vec<u32>,
+    right_counts: HashMap<u32, u32>,
+}
+
+impl LocationLists {
+    /// Read raw string input as two columns of unsigned integers
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (mut left, mut right): (Vec<u32>, Vec<u32>) = input
+            .lines()
+            .map(|l| {
+                let mut parts = l.split_whitespace();
+                let n1 = parse_token::<u32>(
+                    input,
+                    parts.next().unwrap_or(&l[l.len()..]),
+                    "a location ID",
+                )?;
+                let n2 = parse_token::<u32>(
+                    input,
+                    parts.next().unwrap_or(&l[l.len()..]),
+                    "a second location ID",
+                )?;
+                Ok((n1, n2))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .into_iter()
+            .unzip();
+
+        left.sort_unstable();
+        right.sort_unstable();
+        let mut right_counts = HashMap::new();
+        for &id in &right {
+            *right_counts.entry(id).or_default() += 1;
+        }
+
+        Ok(Self {
+            left,
+            right,
+            right_counts,
+        })
+    }
+
+    /// Number of pairs of IDs
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// Pairs of IDs, smallest with smallest, in ascending order
+    pub fn pairs(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        zip(self.left.iter().copied(), self.right.iter().copied())
+    }
+
+    /// Distance between the IDs of each pair, in the order of [`LocationLists::pairs`]
+    pub fn distances(&self) -> impl Iterator<Item = u32> + '_ {
+        self.pairs().map(|(l, r)| l.abs_diff(r))
+    }
+
+    /// Sum of the distances between every pair
+    pub fn total_distance(&self) -> u32 {
+        self.distances().sum()
+    }
+
+    /// Median distance between the IDs of a pair, if there are any pairs
+    pub fn median_distance(&self) -> Option<f64> {
+        let mut distances = self.distances().collect::<Vec<_>>();
+        distances.sort_unstable();
+        let mid = distances.len() / 2;
+        match distances.len() {
+            0 => None,
+            n if n.is_multiple_of(2) => {
+                Some((f64::from(distances[mid - 1]) + f64::from(distances[mid])) / 2.0)
+            }
+            _ => Some(f64::from(distances[mid])),
+        }
+    }
+
+    /// Number of times an ID appears in the right list
+    pub fn right_count(&self, id: u32) -> u32 {
+        self.right_counts.get(&id).copied().unwrap_or_default()
+    }
+
+    /// Sum of each ID in the left list multiplied by the number of times it appears in the
+    /// right list
+    pub fn similarity(&self) -> u32 {
+        self.left.iter().map(|&id| id * self.right_count(id)).sum()
+    }
+
+    /// Up to `n` of the IDs appearing most often across both lists, with the number of
+    /// times each appears; ties are broken by the smaller ID
+    pub fn most_common(&self, n: usize) -> Vec<(u32, u32)> {
+        let mut counts = self.right_counts.clone();
+        for &id in &self.left {
+            *counts.entry(id).or_default() += 1;
+        }
+
+        let mut counts = counts.into_iter().collect::<Vec<_>>();
+        counts.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        counts.truncate(n);
+        counts
+    }
+
+    /// Write the pair count, median distance and most common IDs, followed by a table of
+    /// every pair and its distance
+    pub fn write_summary(&self, out: &mut impl Write) -> std::fmt::Result {
+        writeln!(out, "{} pairs", self.len())?;
+        if let Some(median) = self.median_distance() {
+            writeln!(out, "Median distance: {median}")?;
+        }
+        let common = self
+            .most_common(5)
+            .into_iter()
+            .map(|(id, count)| format!("{id} (x{count})"))
+            .collect::<Vec<_>>();
+        writeln!(out, "Most common IDs: {}", common.join(", "))?;
+        writeln!(out, "{:>8} {:>8} {:>8}", "left", "right", "distance")?;
+        for ((l, r), distance) in zip(self.pairs(), self.distances()) {
+            writeln!(out, "{l:>8} {r:>8} {distance:>8}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = LocationLists;
+    type Answer = u32;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        LocationLists::parse(raw)
+    }
+
+    /// Calculate total distance
+    fn part1(input: &Self::Input) -> u32 {
+        input.total_distance()
+    }
+
+    /// Calculate "similarity score"
+    fn part2(input: &Self::Input) -> u32 {
+        input.similarity()
+    }
+
+    fn explain(input: &Self::Input) -> Option<String> {
+        let mut out = String::new();
+        input
+            .write_summary(&mut out)
+            .expect("could not write summary");
+        Some(out)
+    }
+}
+
+aoc_common::sample_tests!(Day1);
+
+#[cfg(test)]
+mod day1_tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
+
+    #[test]
+    fn parse_error_test() {
+        let err = LocationLists::parse("3   4\n4   x\n2").expect_err("input should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 5, "x"));
+
+        let err = LocationLists::parse("3   4\n2").expect_err("input should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, ""));
+    }
+
+    #[test]
+    fn test_pairs_and_distances() {
+        let lists = LocationLists::parse(SAMPLE).expect("could not parse sample");
+        assert_eq!(lists.len(), 6);
+        assert_eq!(
+            lists.pairs().collect::<Vec<_>>(),
+            vec![(1, 3), (2, 3), (3, 3), (3, 4), (3, 5), (4, 9)]
+        );
+        assert_eq!(
+            lists.distances().collect::<Vec<_>>(),
+            vec![2, 1, 0, 1, 2, 5]
+        );
+    }
+
+    #[test_case::test_case(SAMPLE => Some(1.5) ; "even")]
+    #[test_case::test_case("1   4\n2   2\n7   3\n" => Some(1.0) ; "odd")]
+    #[test_case::test_case("" => None ; "empty")]
+    #[test_case::test_case("0   4294967294\n1   4294967295\n" => Some(4294967294.0) ; "near u32 max")]
+    fn test_median_distance(raw: &str) -> Option<f64> {
+        LocationLists::parse(raw)
+            .expect("could not parse input")
+            .median_distance()
+    }
+
+    #[test]
+    fn test_similarity_counts() {
+        let lists = LocationLists::parse(SAMPLE).expect("could not parse sample");
+        assert_eq!(lists.right_count(3), 3);
+        assert_eq!(lists.right_count(1), 0);
+        assert_eq!(lists.similarity(), 31);
+    }
+
+    #[test]
+    fn test_most_common() {
+        let lists = LocationLists::parse(SAMPLE).expect("could not parse sample");
+        assert_eq!(lists.most_common(3), vec![(3, 6), (4, 2), (1, 1)]);
+        assert_eq!(lists.most_common(10).len(), 6);
+    }
+
+    #[test]
+    fn test_write_summary() {
+        let lists = LocationLists::parse("3   4\n1   3\n").expect("could not parse input");
+        let mut summary = String::new();
+        lists
+            .write_summary(&mut summary)
+            .expect("could not write summary");
+        assert_eq!(
+            summary,
+            "2 pairs
+Median distance: 1.5
+Most common IDs: 3 (x2), 1 (x1), 4 (x1)
+    left    right distance
+       1        3        2
+       3        4        1
+"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use aoc_common::{geometry::Coord, grid::Grid, ParseError, Solution};
+
+type Elevation = usize;
+#[derive(Debug)]
+pub struct Input {
+    map: Grid<Elevation>,
+}
+
+impl Input {
+    fn next_steps(&self, coord: Coord) -> Vec<Coord> {
+        let current_elevation = self.map[coord];
+        self.map
+            .neighbours4(coord)
+            .filter(|(_, next_elevation)| **next_elevation == current_elevation + 1)
+            .map(|(next_coord, _)| next_coord)
+            .collect()
+    }
+
+    fn trailheads(&self) -> Vec<Coord> {
+        self.map
+            .iter()
+            .filter_map(
+                |(coord, elevation)| {
+                    if *elevation == 0 {
+                        Some(coord)
+                    } else {
+                        None
+                    }
+                },
+            )
+            .collect::<Vec<_>>()
+    }
+}
+
+fn parse_input(raw: &str) -> Result<Input, ParseError> {
+    let map = Grid::parse(raw, "an elevation digit", |num| {
+        num.to_digit(10).map(|elevation| elevation as usize)
+    })?;
+    Ok(Input { map })
+}
+
+fn walk_path(input: &Input, coord: Coord, trail_ends: &mut HashSet<Coord>, rating: &mut usize) {
+    //  end of path
+    if input.map[coord] == 9 {
+        trail_ends.insert(coord); // add coord to list of trail ends
+        *rating += 1; // increment rating for path
+        return; // stop recursion
+    }
+
+    // For each valid neighbour, recurse
+    for c in input.next_steps(coord) {
+        walk_path(input, c, trail_ends, rating)
+    }
+}
+
+fn part1(input: &Input) -> usize {
+    let mut score = 0_usize;
+    for trailhead in input.trailheads() {
+        let mut trail_ends = HashSet::new();
+        walk_path(input, trailhead, &mut trail_ends, &mut 0);
+        score += trail_ends.len()
+    }
+    score
+}
+
+fn part2(input: &Input) -> usize {
+    let mut rating = 0_usize;
+    for trailhead in input.trailheads() {
+        let mut trail_ends = HashSet::new();
+        walk_path(input, trailhead, &mut trail_ends, &mut rating);
+    }
+    rating
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+aoc_common::sample_tests!(Day10);
+
+#[cfg(test)]
+mod day10_tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let input = parse_input(SAMPLE).expect("could not parse sample");
+        assert_eq!((input.map.width(), input.map.height()), (8, 8));
+        assert_eq!(input.map[Coord::new(1, 1)], 8);
+        assert_eq!(input.map[Coord::new(1, 2)], 1);
+        assert_eq!(input.map[Coord::new(2, 1)], 7);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("0123\n1.34").expect_err("map should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "."));
+    }
+}
@@ -1,17 +1,17 @@
+use aoc_common::{parse::parse_token, ParseError, Solution};
 use cached::proc_macro::cached;
 
-const RAW: &str = include_str!("../input.txt");
-type Input = Vec<usize>;
+pub type Input = Vec<usize>;
 
-fn parse_input(raw: &str) -> Input {
+fn parse_input(raw: &str) -> Result<Input, ParseError> {
     raw.split_whitespace()
-        .map(|c| c.parse().expect("could not parse as number"))
+        .map(|c| parse_token(raw, c, "a stone number"))
         .collect()
 }
 
 // return true if number of digits is even
 fn has_even_digits(n: usize) -> bool {
-    n_digits(n) % 2 == 0
+    n_digits(n).is_multiple_of(2)
 }
 
 // count digits in stone value
@@ -42,7 +42,7 @@ fn recursive_count_stone_after_blinks(stone: usize, n_blinks: usize) -> usize {
 
 // get the total number of stones after given number of blinks
 fn count_all_stones_after_blinks(stones: &Input, n_blinks: usize) -> usize {
-    stones.into_iter().fold(0, |acc, &elem| {
+    stones.iter().fold(0, |acc, &elem| {
         acc + recursive_count_stone_after_blinks(elem, n_blinks)
     })
 }
@@ -66,23 +66,42 @@ fn part2(input: &Input) -> usize {
     count_all_stones_after_blinks(input, 75)
 }
 
-fn main() {
-    let input = parse_input(RAW);
+pub struct Day11;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
 }
 
+aoc_common::sample_tests!(Day11);
+
 #[cfg(test)]
 mod day11_tests {
     use super::*;
 
-    const SAMPLE: &str = include_str!("../sample.txt");
-
     #[test]
     fn test_parse_input() {
         let input = parse_input("1 2024 1 0 9 9 2021976");
-        assert_eq!(input, vec![1, 2024, 1, 0, 9, 9, 2021976])
+        assert_eq!(input, Ok(vec![1, 2024, 1, 0, 9, 9, 2021976]))
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("125 17 -3").expect_err("stone should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 8, "-3"));
     }
 
     #[test_case::test_case(125, 1 => 1)]
@@ -119,12 +138,4 @@ mod day11_tests {
     fn test_blink_at_stone(stone: usize) -> Vec<usize> {
         blink_at_stone(stone)
     }
-
-    #[test]
-    fn test_part1() {
-        let input = parse_input(SAMPLE);
-        let expected = 55312;
-
-        assert_eq!(part1(&input), expected);
-    }
 }
@@ -0,0 +1,215 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+use aoc_common::{
+    geometry::{Coord, Direction4},
+    grid::Grid,
+    ParseError, Solution,
+};
+
+type NumNeighbours = usize;
+
+struct Region {
+    crop: char,
+    coords: HashMap<Coord, NumNeighbours>,
+}
+
+impl Region {
+    fn perimeter(&self) -> usize {
+        // for each coord, it adds n perimeters where n = 4-(similar_neighbours)
+        self.coords.values().map(|n| 4 - n).sum()
+    }
+
+    fn area(&self) -> usize {
+        self.coords.len()
+    }
+
+    fn fence_cost(&self) -> usize {
+        self.area() * self.perimeter()
+    }
+
+    fn new_fence_cost(&self) -> usize {
+        self.area() * self.sides()
+    }
+
+    fn sides(&self) -> usize {
+        // a polygon has as many sides as it has corners, so count the corners of each plot
+        let in_region = |c: Coord| self.coords.contains_key(&c);
+        self.coords
+            .keys()
+            .map(|&coord| {
+                Direction4::ALL
+                    .into_iter()
+                    .filter(|&dir| {
+                        let side = in_region(coord.step(dir));
+                        let next_side = in_region(coord.step(dir.turn_right()));
+                        let diagonal = in_region(coord.step(dir) + dir.turn_right().delta());
+                        // outer corner: fenced on both sides, or inner corner: the region wraps
+                        // around the diagonal plot
+                        (!side && !next_side) || (side && next_side && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+impl Debug for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Region of {crop} plants with price {area} * {perimeter} = {price}",
+            crop = self.crop,
+            area = self.area(),
+            perimeter = self.perimeter(),
+            price = self.fence_cost()
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    plot_map: Grid<char>,
+}
+
+impl Input {
+    fn regions(&self) -> Vec<Region> {
+        let mut identified_coords = HashSet::new();
+        let mut regions = vec![];
+        for (coord, crop) in self.plot_map.iter() {
+            // if we haven't already placed this coord in a region, check it
+            if !identified_coords.contains(&coord) {
+                let mut similar_neighbours = HashMap::new();
+                self.recursive_similar_neighbours(coord, &mut similar_neighbours);
+                for (c, _) in similar_neighbours.iter() {
+                    identified_coords.insert(*c);
+                }
+                let region = Region {
+                    crop: *crop,
+                    coords: similar_neighbours,
+                };
+                regions.push(region);
+            }
+        }
+        regions
+    }
+
+    fn recursive_similar_neighbours(
+        &self,
+        coord: Coord,
+        similar_neighbours: &mut HashMap<Coord, NumNeighbours>,
+    ) {
+        let crop = self.plot_map[coord];
+        let neighbours_with_same_crop = self
+            .plot_map
+            .neighbours4(coord)
+            .filter(|(_, &neighbour_crop)| neighbour_crop == crop)
+            .map(|(neighbour, _)| neighbour)
+            .collect::<Vec<_>>();
+
+        // TODO: filter
+        // !similar_neighbours.contains_key(&neighbour)
+        // println!(
+        //     "similar neighbours to {:?}: {:?}",
+        //     coord, neighbours_with_same_crop
+        // );
+        similar_neighbours.insert(coord, neighbours_with_same_crop.len());
+
+        // recurse
+        for neighbour in neighbours_with_same_crop {
+            if !similar_neighbours.contains_key(&neighbour) {
+                self.recursive_similar_neighbours(neighbour, similar_neighbours);
+            }
+        }
+    }
+}
+
+fn parse_input(raw: &str) -> Result<Input, ParseError> {
+    Ok(Input {
+        plot_map: Grid::parse(raw, "a crop", Some)?,
+    })
+}
+
+fn part1(input: &Input) -> usize {
+    input.regions().iter().map(|r| r.fence_cost()).sum()
+}
+
+fn part2(input: &Input) -> usize {
+    input.regions().iter().map(|r| r.new_fence_cost()).sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+aoc_common::sample_tests!(Day12);
+
+#[cfg(test)]
+mod day12_tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let input = parse_input(SAMPLE).expect("could not parse sample");
+        assert_eq!(input.plot_map.coords().count(), 100)
+    }
+
+    #[test]
+    fn test_regions() {
+        let input = parse_input(SAMPLE).expect("could not parse sample");
+        let regions = input.regions();
+        // for r in regions.iter() {
+        //     dbg!(r);
+        // }
+        assert_eq!(regions.len(), 11);
+    }
+
+    #[test]
+    fn test_similar_neighbours() {
+        let input = parse_input(
+            r#"RRRR.
+RRRR.
+..RRR
+..R..
+"#,
+        )
+        .expect("could not parse map");
+        assert_eq!(input.plot_map.coords().count(), 20);
+        let mut similar_neighbours = HashMap::new();
+        input.recursive_similar_neighbours(Coord::new(0, 0), &mut similar_neighbours);
+    }
+
+    #[test_case::test_case("AAAA\nBBCD\nBBCC\nEEEC" => vec![('A', 4), ('B', 4), ('C', 8), ('D', 4), ('E', 4)] ; "small")]
+    #[test_case::test_case("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE" => vec![('E', 12), ('X', 4), ('X', 4)] ; "e shaped")]
+    #[test_case::test_case("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA" => vec![('A', 12), ('B', 4), ('B', 4)] ; "nested regions")]
+    fn test_sides(raw: &str) -> Vec<(char, usize)> {
+        let input = parse_input(raw).expect("could not parse map");
+        let mut sides = input
+            .regions()
+            .iter()
+            .map(|r| (r.crop, r.sides()))
+            .collect::<Vec<_>>();
+        sides.sort();
+        sides
+    }
+}
@@ -0,0 +1,412 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Write},
+};
+
+use aoc_common::{parse::parse_token, ParseError, Solution};
+
+type Level = u8;
+type Report = Vec<Level>;
+
+/// Direction the levels of a safe report all move in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+}
+
+impl Trend {
+    const ALL: [Trend; 2] = [Trend::Increasing, Trend::Decreasing];
+
+    /// Trend from one level to the next, if they differ
+    fn between(a: Level, b: Level) -> Option<Self> {
+        match b.cmp(&a) {
+            Ordering::Greater => Some(Self::Increasing),
+            Ordering::Less => Some(Self::Decreasing),
+            Ordering::Equal => None,
+        }
+    }
+}
+
+impl Display for Trend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Increasing => write!(f, "increasing"),
+            Self::Decreasing => write!(f, "decreasing"),
+        }
+    }
+}
+
+/// Rule broken by a pair of levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// The levels move against the report's trend
+    Trend(Trend),
+    /// The levels change by the given step, outside the policy's bounds
+    Step(u8),
+}
+
+/// Pair of neighbouring levels that break a rule, by their indices in the report
+///
+/// The levels are only neighbours once any removed levels between them are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub indices: (usize, usize),
+    pub rule: Rule,
+}
+
+impl Violation {
+    /// Describe the violation in terms of the report's levels
+    fn describe(&self, report: &[Level]) -> String {
+        let (a, b) = self.indices;
+        let levels = format!("levels {a} and {b} ({} -> {})", report[a], report[b]);
+        match self.rule {
+            Rule::Trend(trend) => format!("{levels} break the {trend} trend"),
+            Rule::Step(step) => format!("{levels} change by {step}"),
+        }
+    }
+}
+
+/// Whether a report is safe under a [`SafetyPolicy`], and if not, why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportVerdict {
+    /// Every level follows the policy as it is
+    Safe(Trend),
+    /// The report breaks the policy because of `violation`, but removing the levels at
+    /// `removed` makes it safe
+    Dampened {
+        removed: Vec<usize>,
+        trend: Trend,
+        violation: Violation,
+    },
+    /// The report breaks the policy, and no allowed number of removals can fix it
+    Unsafe(Violation),
+}
+
+impl ReportVerdict {
+    /// Whether the report is safe under the policy it was judged by, removals included
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Self::Unsafe(_))
+    }
+
+    /// Describe the verdict in terms of the report's levels
+    pub fn describe(&self, report: &[Level]) -> String {
+        match self {
+            Self::Safe(trend) => format!("safe, {trend}"),
+            Self::Dampened {
+                removed,
+                trend,
+                violation,
+            } => {
+                let removed = removed
+                    .iter()
+                    .map(|&idx| format!("{idx} ({})", report[idx]))
+                    .collect::<Vec<_>>();
+                let plural = if removed.len() == 1 { "" } else { "s" };
+                format!(
+                    "safe, {trend}, after removing level{plural} {}; {}",
+                    removed.join(", "),
+                    violation.describe(report)
+                )
+            }
+            Self::Unsafe(violation) => format!("unsafe; {}", violation.describe(report)),
+        }
+    }
+}
+
+/// Rules a report's levels must follow to count as safe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// Smallest change allowed between neighbouring levels; at 0, a level may repeat
+    pub min_step: u8,
+    /// Largest change allowed between neighbouring levels
+    pub max_step: u8,
+    /// Trend every report must follow; if `None`, each report may follow either
+    pub trend: Option<Trend>,
+    /// Number of levels the Problem Dampener may remove to make a report safe
+    pub max_removals: usize,
+}
+
+impl SafetyPolicy {
+    /// The rules from part 1
+    pub const STRICT: Self = Self {
+        min_step: 1,
+        max_step: 3,
+        trend: None,
+        max_removals: 0,
+    };
+
+    /// The rules from part 2, where the Problem Dampener may remove a single level
+    pub const DAMPENED: Self = Self {
+        max_removals: 1,
+        ..Self::STRICT
+    };
+
+    /// First pair of levels breaking a rule, or the report's trend if there is none
+    ///
+    /// The levels at `skip` are left out. If no trend is required, it is taken from the first
+    /// two levels that differ; a report with no such levels counts as increasing.
+    fn first_violation(
+        &self,
+        report: &[Level],
+        skip: &[usize],
+        trend: Option<Trend>,
+    ) -> Result<Trend, Violation> {
+        let mut levels = report
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(idx, _)| !skip.contains(idx));
+        let mut trend = trend;
+        let Some(mut prev) = levels.next() else {
+            return Ok(trend.unwrap_or(Trend::Increasing));
+        };
+
+        for (idx, level) in levels {
+            let violation = |rule| Violation {
+                indices: (prev.0, idx),
+                rule,
+            };
+            let step = prev.1.abs_diff(level);
+            if !(self.min_step..=self.max_step).contains(&step) {
+                return Err(violation(Rule::Step(step)));
+            }
+            match (Trend::between(prev.1, level), trend) {
+                (Some(actual), Some(expected)) if actual != expected => {
+                    return Err(violation(Rule::Trend(expected)))
+                }
+                (Some(actual), _) => trend = Some(actual),
+                (None, _) => {}
+            }
+            prev = (idx, level);
+        }
+        Ok(trend.unwrap_or(Trend::Increasing))
+    }
+
+    /// Search for up to `budget` more levels to remove, on top of those in `removed`, that
+    /// make the report follow `trend`
+    ///
+    /// Any set of removals that fixes the report must include one of the levels of the first
+    /// pair still breaking a rule, so only those two need trying at each step.
+    fn repair(
+        &self,
+        report: &[Level],
+        trend: Trend,
+        removed: &mut Vec<usize>,
+        budget: usize,
+    ) -> bool {
+        let Err(violation) = self.first_violation(report, removed, Some(trend)) else {
+            return true;
+        };
+        if budget == 0 {
+            return false;
+        }
+        for idx in [violation.indices.0, violation.indices.1] {
+            removed.push(idx);
+            if self.repair(report, trend, removed, budget - 1) {
+                return true;
+            }
+            removed.pop();
+        }
+        false
+    }
+
+    /// Judge a report against the policy
+    ///
+    /// Takes time linear in the report's length for a fixed number of removals `k`, as at
+    /// most `2^k` sets of removals are tried for each trend.
+    pub fn judge(&self, report: &[Level]) -> ReportVerdict {
+        let violation = match self.first_violation(report, &[], self.trend) {
+            Ok(trend) => return ReportVerdict::Safe(trend),
+            Err(violation) => violation,
+        };
+
+        let trends = match self.trend {
+            Some(trend) => vec![trend],
+            None => Trend::ALL.to_vec(),
+        };
+        for trend in trends {
+            let mut removed = vec![];
+            if self.repair(report, trend, &mut removed, self.max_removals) {
+                removed.sort_unstable();
+                return ReportVerdict::Dampened {
+                    removed,
+                    trend,
+                    violation,
+                };
+            }
+        }
+        ReportVerdict::Unsafe(violation)
+    }
+
+    /// Number of reports that are safe under the policy
+    pub fn count_safe(&self, reports: &[Report]) -> usize {
+        reports.iter().filter(|r| self.judge(r).is_safe()).count()
+    }
+
+    /// Write each report's levels alongside a description of its verdict under the policy
+    pub fn write_verdicts(&self, reports: &[Report], out: &mut impl Write) -> std::fmt::Result {
+        for report in reports {
+            let levels = report
+                .iter()
+                .map(Level::to_string)
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(out, "{levels}: {}", self.judge(report).describe(report))?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self::STRICT
+    }
+}
+
+fn parse_input(raw: &str) -> Result<Vec<Report>, ParseError> {
+    raw.lines()
+        .map(|line| -> Result<Report, ParseError> {
+            line.split_whitespace()
+                .map(|num| -> Result<Level, ParseError> { parse_token(raw, num, "a level") })
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Report>;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        SafetyPolicy::STRICT.count_safe(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        SafetyPolicy::DAMPENED.count_safe(input)
+    }
+
+    fn explain(input: &Self::Input) -> Option<String> {
+        let mut out = String::new();
+        SafetyPolicy::DAMPENED
+            .write_verdicts(input, &mut out)
+            .expect("could not write verdicts");
+        Some(out)
+    }
+}
+
+aoc_common::sample_tests!(Day2);
+
+#[cfg(test)]
+mod day2_tests {
+    use super::*;
+
+    const RAW: &str = include_str!("../samples/sample.txt");
+
+    #[test]
+    fn test_parse() {
+        let parsed = parse_input(RAW).expect("could not parse sample");
+        let expected = vec![
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![9, 7, 6, 2, 1],
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![1, 3, 6, 7, 9],
+        ];
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("7 6 4\n1 2 300").expect_err("level should be out of range");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 5, "300"));
+    }
+
+    fn violation(a: usize, b: usize, rule: Rule) -> Violation {
+        Violation {
+            indices: (a, b),
+            rule,
+        }
+    }
+
+    #[test_case::test_case(&[7, 6, 4, 2, 1] => ReportVerdict::Safe(Trend::Decreasing))]
+    #[test_case::test_case(&[1, 2, 7, 8, 9] => ReportVerdict::Unsafe(violation(1, 2, Rule::Step(5))))]
+    #[test_case::test_case(&[9, 7, 6, 2, 1] => ReportVerdict::Unsafe(violation(2, 3, Rule::Step(4))))]
+    #[test_case::test_case(&[1, 3, 2, 4, 5] => ReportVerdict::Dampened { removed: vec![1], trend: Trend::Increasing, violation: violation(1, 2, Rule::Trend(Trend::Increasing)) })]
+    #[test_case::test_case(&[8, 6, 4, 4, 1] => ReportVerdict::Dampened { removed: vec![2], trend: Trend::Decreasing, violation: violation(2, 3, Rule::Step(0)) })]
+    #[test_case::test_case(&[1, 3, 6, 7, 9] => ReportVerdict::Safe(Trend::Increasing))]
+    #[test_case::test_case(&[5, 1, 2, 3] => ReportVerdict::Dampened { removed: vec![0], trend: Trend::Increasing, violation: violation(0, 1, Rule::Step(4)) } ; "first level sets the wrong trend")]
+    fn test_judge_dampened(report: &[Level]) -> ReportVerdict {
+        SafetyPolicy::DAMPENED.judge(report)
+    }
+
+    #[test_case::test_case(SafetyPolicy { max_removals: 2, ..SafetyPolicy::STRICT }, &[1, 9, 2, 9, 3] => ReportVerdict::Dampened { removed: vec![1, 3], trend: Trend::Increasing, violation: violation(0, 1, Rule::Step(8)) } ; "two removals")]
+    #[test_case::test_case(SafetyPolicy { max_removals: 2, ..SafetyPolicy::STRICT }, &[1, 9, 2, 9, 3, 9] => matches ReportVerdict::Unsafe(_) ; "three bad levels")]
+    #[test_case::test_case(SafetyPolicy { trend: Some(Trend::Increasing), ..SafetyPolicy::DAMPENED }, &[7, 6, 4, 2, 1] => matches ReportVerdict::Unsafe(Violation { rule: Rule::Trend(Trend::Increasing), .. }) ; "required trend")]
+    #[test_case::test_case(SafetyPolicy { min_step: 0, max_step: 5, ..SafetyPolicy::STRICT }, &[1, 1, 6, 6, 2] => matches ReportVerdict::Unsafe(Violation { indices: (3, 4), rule: Rule::Trend(Trend::Increasing) }) ; "wider steps")]
+    #[test_case::test_case(SafetyPolicy { min_step: 0, max_step: 5, ..SafetyPolicy::STRICT }, &[1, 1, 6, 6] => ReportVerdict::Safe(Trend::Increasing) ; "repeated levels")]
+    fn test_judge(policy: SafetyPolicy, report: &[Level]) -> ReportVerdict {
+        policy.judge(report)
+    }
+
+    /// Whether removing some combination of up to `k` levels makes a report safe, by trying
+    /// every one
+    fn brute_force(policy: &SafetyPolicy, report: &[Level], k: usize) -> bool {
+        if policy.first_violation(report, &[], policy.trend).is_ok() {
+            return true;
+        }
+        k > 0
+            && (0..report.len()).any(|i| {
+                let mut removed = report.to_vec();
+                removed.remove(i);
+                brute_force(policy, &removed, k - 1)
+            })
+    }
+
+    #[test_case::test_case(SafetyPolicy::STRICT ; "strict")]
+    #[test_case::test_case(SafetyPolicy::DAMPENED ; "dampened")]
+    #[test_case::test_case(SafetyPolicy { max_removals: 2, ..SafetyPolicy::STRICT } ; "two removals")]
+    #[test_case::test_case(SafetyPolicy { trend: Some(Trend::Decreasing), ..SafetyPolicy::DAMPENED } ; "decreasing")]
+    fn test_judge_matches_brute_force(policy: SafetyPolicy) {
+        // every report of five levels from 1 to 6
+        for n in 0..6_usize.pow(5) {
+            let report = (0..5)
+                .map(|i| (n / 6_usize.pow(i) % 6 + 1) as Level)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                policy.judge(&report).is_safe(),
+                brute_force(&policy, &report, policy.max_removals),
+                "{report:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        let report = [1, 3, 2, 4, 5];
+        assert_eq!(
+            SafetyPolicy::DAMPENED.judge(&report).describe(&report),
+            "safe, increasing, after removing level 1 (3); levels 1 and 2 (3 -> 2) break the increasing trend"
+        );
+    }
+
+    #[test]
+    fn test_write_verdicts() {
+        let mut verdicts = String::new();
+        SafetyPolicy::DAMPENED
+            .write_verdicts(&[vec![1, 3, 2, 4, 5]], &mut verdicts)
+            .expect("could not write verdicts");
+        assert_eq!(
+            verdicts,
+            "1 3 2 4 5: safe, increasing, after removing level 1 (3); levels 1 and 2 (3 -> 2) break the increasing trend\n"
+        );
+    }
+}
@@ -0,0 +1,371 @@
+//! Streaming lexer picking instructions out of corrupted memory, and reporting the
+//! near-misses it rejects along the way.
+use std::{fmt::Display, ops::Range};
+
+/// An instruction recognised in corrupted memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mul(a, b) => write!(f, "mul({a},{b})"),
+            Self::Do => write!(f, "do()"),
+            Self::Dont => write!(f, "don't()"),
+        }
+    }
+}
+
+/// Name of an instruction, the number of operands it takes, and how to build it from them
+#[derive(Debug, Clone, Copy)]
+pub struct Opcode {
+    pub name: &'static str,
+    pub arity: usize,
+    build: fn(&[u32]) -> Instruction,
+}
+
+/// Every opcode the lexer recognises
+///
+/// Supporting a new instruction means adding its opcode here and teaching the
+/// [`Machine`](crate::Machine) to run it.
+pub const OPCODES: &[Opcode] = &[
+    Opcode {
+        name: "mul",
+        arity: 2,
+        build: |operands| Instruction::Mul(operands[0], operands[1]),
+    },
+    Opcode {
+        name: "do",
+        arity: 0,
+        build: |_| Instruction::Do,
+    },
+    Opcode {
+        name: "don't",
+        arity: 0,
+        build: |_| Instruction::Dont,
+    },
+];
+
+/// Most digits an operand may have
+const MAX_DIGITS: usize = 3;
+
+/// An instruction along with the byte range of memory it was read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub instruction: Instruction,
+    pub span: Range<usize>,
+}
+
+/// Why a near-miss was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// Something else was found where the given punctuation was needed
+    Expected { expected: char, found: Option<char> },
+    /// An operand was needed, but something else was found
+    MissingOperand { found: Option<char> },
+    /// An operand had more digits than allowed
+    OperandTooLong { digits: usize },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let describe = |found: &Option<char>| match found {
+            Some(c) => format!("{c:?}"),
+            None => "the end of memory".to_owned(),
+        };
+        match self {
+            Self::Expected { expected, found } => {
+                write!(f, "expected {expected:?}, found {}", describe(found))
+            }
+            Self::MissingOperand { found } => {
+                write!(f, "expected an operand, found {}", describe(found))
+            }
+            Self::OperandTooLong { digits } => write!(
+                f,
+                "operand has {digits} digits, more than the {MAX_DIGITS} allowed"
+            ),
+        }
+    }
+}
+
+/// An opcode's name that was not followed by a valid instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearMiss<'a> {
+    pub opcode: &'static str,
+    /// Memory from the start of the opcode's name up to and including the first byte that
+    /// could not be read
+    pub text: &'a str,
+    pub span: Range<usize>,
+    pub rejection: Rejection,
+}
+
+impl Display for NearMiss<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}..{} {:?}: {}",
+            self.span.start, self.span.end, self.text, self.rejection
+        )
+    }
+}
+
+/// Point at which reading an instruction failed, and why
+struct Failure {
+    end: usize,
+    rejection: Rejection,
+}
+
+/// Iterator over the instructions in corrupted memory, in the order they appear
+///
+/// An instruction is its opcode's name followed by its operands in parentheses, separated
+/// by commas, each of 1 to 3 digits. Anything else is skipped over, although
+/// [`Lexer::diagnose`] reports where an opcode's name was found without a valid instruction.
+pub struct Lexer<'a> {
+    memory: &'a str,
+    pos: usize,
+    /// Operands of the instruction being read, kept to avoid allocating for each one
+    operands: Vec<u32>,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(memory: &'a str) -> Self {
+        Self {
+            memory,
+            pos: 0,
+            operands: vec![],
+        }
+    }
+
+    /// Iterate over near-misses as well as instructions
+    pub fn diagnose(self) -> Diagnose<'a> {
+        Diagnose(self)
+    }
+
+    /// Character at `pos`, if memory doesn't end before it
+    fn found(&self, pos: usize) -> Option<char> {
+        self.memory.get(pos..).and_then(|rest| rest.chars().next())
+    }
+
+    /// Failure to read what was found at `pos`
+    fn fail(&self, pos: usize, rejection: Rejection) -> Failure {
+        Failure {
+            end: pos + self.found(pos).map_or(0, char::len_utf8),
+            rejection,
+        }
+    }
+
+    /// Position after the expected character at `pos`
+    fn expect(&self, pos: usize, expected: char) -> Result<usize, Failure> {
+        match self.found(pos) {
+            Some(c) if c == expected => Ok(pos + c.len_utf8()),
+            found => Err(self.fail(pos, Rejection::Expected { expected, found })),
+        }
+    }
+
+    /// Read an instruction with the given opcode from `start`, returning it along with the
+    /// end of its span, or where and why reading it failed
+    ///
+    /// Returns `None` if the opcode's name is not at `start` at all.
+    fn read(
+        &mut self,
+        opcode: &Opcode,
+        start: usize,
+    ) -> Option<Result<(Instruction, usize), Failure>> {
+        self.memory.as_bytes()[start..]
+            .starts_with(opcode.name.as_bytes())
+            .then(|| self.read_operands(opcode, start + opcode.name.len()))
+    }
+
+    /// Read the parenthesised operands following an opcode's name at `pos`
+    fn read_operands(
+        &mut self,
+        opcode: &Opcode,
+        pos: usize,
+    ) -> Result<(Instruction, usize), Failure> {
+        let mut pos = self.expect(pos, '(')?;
+
+        self.operands.clear();
+        for idx in 0..opcode.arity {
+            if idx > 0 {
+                pos = self.expect(pos, ',')?;
+            }
+            let digits = self.memory.as_bytes()[pos..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            if digits == 0 {
+                let found = self.found(pos);
+                return Err(self.fail(pos, Rejection::MissingOperand { found }));
+            }
+            if digits > MAX_DIGITS {
+                return Err(Failure {
+                    end: pos + digits,
+                    rejection: Rejection::OperandTooLong { digits },
+                });
+            }
+            let operand = self.memory.as_bytes()[pos..pos + digits]
+                .iter()
+                .fold(0, |n, b| n * 10 + u32::from(b - b'0'));
+            self.operands.push(operand);
+            pos += digits;
+        }
+        let end = self.expect(pos, ')')?;
+
+        Ok(((opcode.build)(&self.operands), end))
+    }
+
+    /// Next instruction or near-miss, whichever comes first
+    ///
+    /// A near-miss is reported where at least one opcode's name is found but none can be
+    /// read, by the opcode that got furthest.
+    fn next_item(&mut self) -> Option<Result<Token, NearMiss<'a>>> {
+        while self.pos < self.memory.len() {
+            let start = self.pos;
+            self.pos += 1;
+            let mut furthest: Option<(&'static str, Failure)> = None;
+            for opcode in OPCODES {
+                match self.read(opcode, start) {
+                    Some(Ok((instruction, end))) => {
+                        self.pos = end;
+                        return Some(Ok(Token {
+                            instruction,
+                            span: start..end,
+                        }));
+                    }
+                    Some(Err(failure))
+                        if furthest.as_ref().is_none_or(|(_, f)| failure.end > f.end) =>
+                    {
+                        furthest = Some((opcode.name, failure));
+                    }
+                    _ => {}
+                }
+            }
+            if let Some((opcode, failure)) = furthest {
+                return Some(Err(NearMiss {
+                    opcode,
+                    text: &self.memory[start..failure.end],
+                    span: start..failure.end,
+                    rejection: failure.rejection,
+                }));
+            }
+        }
+        None
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Ok(token) = self.next_item()? {
+                return Some(token);
+            }
+        }
+    }
+}
+
+/// Iterator over both the instructions and the near-misses in corrupted memory, in the
+/// order they appear
+pub struct Diagnose<'a>(Lexer<'a>);
+
+impl<'a> Iterator for Diagnose<'a> {
+    type Item = Result<Token, NearMiss<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_item()
+    }
+}
+
+#[cfg(test)]
+mod lexer_tests {
+    use super::*;
+
+    fn tokens(memory: &str) -> Vec<(Instruction, Range<usize>)> {
+        Lexer::new(memory)
+            .map(|token| (token.instruction, token.span))
+            .collect()
+    }
+
+    #[test]
+    fn test_spans() {
+        assert_eq!(
+            tokens("xmul(2,4)don't()_do()mul(123,7)"),
+            vec![
+                (Instruction::Mul(2, 4), 1..9),
+                (Instruction::Dont, 9..16),
+                (Instruction::Do, 17..21),
+                (Instruction::Mul(123, 7), 21..31),
+            ]
+        );
+    }
+
+    #[test_case::test_case("mul(1234,5)" ; "too many digits")]
+    #[test_case::test_case("mul(4*" ; "unterminated")]
+    #[test_case::test_case("mul ( 2 , 4 )" ; "spaces")]
+    #[test_case::test_case("mul(2,4]" ; "wrong bracket")]
+    #[test_case::test_case("mul(2)" ; "missing operand")]
+    #[test_case::test_case("mul(2,4,6)" ; "extra operand")]
+    #[test_case::test_case("do(1,2)" ; "operands to do")]
+    #[test_case::test_case("do_not()" ; "misspelt")]
+    fn test_rejected(memory: &str) {
+        assert_eq!(tokens(memory), vec![]);
+    }
+
+    #[test]
+    fn test_overlapping_candidates() {
+        // a failed candidate doesn't hide an instruction starting inside it
+        assert_eq!(
+            tokens("mul(1,mul(2,3)"),
+            vec![(Instruction::Mul(2, 3), 6..14)]
+        );
+    }
+
+    #[test_case::test_case("mul(4*" => (0..6, "expected ',', found '*'".to_owned()) ; "unterminated")]
+    #[test_case::test_case("mul(1234,5)" => (0..8, "operand has 4 digits, more than the 3 allowed".to_owned()) ; "too many digits")]
+    #[test_case::test_case("do_not()" => (0..3, "expected '(', found '_'".to_owned()) ; "misspelt")]
+    #[test_case::test_case("don't(x)" => (0..7, "expected ')', found 'x'".to_owned()) ; "furthest opcode")]
+    #[test_case::test_case("mul(,2)" => (0..5, "expected an operand, found ','".to_owned()) ; "missing operand")]
+    #[test_case::test_case("mul(2,4" => (0..7, "expected ')', found the end of memory".to_owned()) ; "end of memory")]
+    #[test_case::test_case("mul(2,é)" => (0..8, "expected an operand, found 'é'".to_owned()) ; "multibyte")]
+    fn test_near_miss(memory: &str) -> (Range<usize>, String) {
+        let misses = Lexer::new(memory)
+            .diagnose()
+            .filter_map(Result::err)
+            .collect::<Vec<_>>();
+        assert_eq!(misses.len(), 1, "{misses:?}");
+        assert_eq!(misses[0].text, &memory[misses[0].span.clone()]);
+        (misses[0].span.clone(), misses[0].rejection.to_string())
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let items = Lexer::new("mul(1,mul(2,3)do()")
+            .diagnose()
+            .map(|item| match item {
+                Ok(token) => format!("{}", token.instruction),
+                Err(miss) => format!("{miss}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            vec![
+                "0..7 \"mul(1,m\": expected an operand, found 'm'",
+                "mul(2,3)",
+                "do()",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for instruction in [Instruction::Mul(11, 8), Instruction::Do, Instruction::Dont] {
+            let memory = instruction.to_string();
+            assert_eq!(tokens(&memory), vec![(instruction, 0..memory.len())]);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+mod lexer;
+mod machine;
+
+use std::fmt::Write;
+
+use aoc_common::{ParseError, Solution};
+
+pub use lexer::{Diagnose, Instruction, Lexer, NearMiss, Opcode, Rejection, Token, OPCODES};
+pub use machine::Machine;
+
+/// Corrupted memory along with the instructions found in it
+pub struct Program {
+    memory: String,
+    tokens: Vec<Token>,
+}
+
+impl Program {
+    pub fn new(memory: &str) -> Self {
+        Self {
+            memory: memory.to_owned(),
+            tokens: Lexer::new(memory).collect(),
+        }
+    }
+
+    /// Instructions found in the memory, in order
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    pub fn instructions(&self) -> impl Iterator<Item = Instruction> + '_ {
+        self.tokens.iter().map(|t| t.instruction)
+    }
+
+    /// Write a table of every instruction as it runs, interleaved with the near-misses that
+    /// were rejected and why
+    pub fn write_trace(&self, out: &mut impl Write) -> std::fmt::Result {
+        writeln!(
+            out,
+            "{:>13}  {:<16} {:<8} {:>8}",
+            "bytes", "instruction", "enabled", "total"
+        )?;
+        let mut machine = Machine::new();
+        let mut misses = 0;
+        for item in Lexer::new(&self.memory).diagnose() {
+            match item {
+                Ok(token) => {
+                    machine.step(token.instruction);
+                    writeln!(
+                        out,
+                        "{:>13}  {:<16} {:<8} {:>8}",
+                        format!("{}..{}", token.span.start, token.span.end),
+                        token.instruction.to_string(),
+                        machine.enabled,
+                        machine.accumulator
+                    )?;
+                }
+                Err(miss) => {
+                    misses += 1;
+                    writeln!(
+                        out,
+                        "{:>13}  {:<16} rejected: {}",
+                        format!("{}..{}", miss.span.start, miss.span.end),
+                        format!("{:?}", miss.text),
+                        miss.rejection
+                    )?;
+                }
+            }
+        }
+        writeln!(
+            out,
+            "{} instructions, {misses} near-misses",
+            self.tokens.len()
+        )
+    }
+
+    /// Places in memory that look like an instruction but could not be read as one
+    pub fn near_misses(&self) -> impl Iterator<Item = NearMiss<'_>> {
+        Lexer::new(&self.memory).diagnose().filter_map(Result::err)
+    }
+}
+
+fn part1(program: &Program) -> u32 {
+    Machine::without_conditionals().run(program.instructions())
+}
+
+fn part2(program: &Program) -> u32 {
+    Machine::new().run(program.instructions())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Program;
+    type Answer = u32;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        // anything that isn't an instruction is just more corrupted memory
+        Ok(Program::new(raw))
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+
+    /// Trace every instruction as it runs, interleaved with the near-misses that were
+    /// rejected and why
+    fn explain(input: &Self::Input) -> Option<String> {
+        let mut out = String::new();
+        input.write_trace(&mut out).expect("could not write trace");
+        Some(out)
+    }
+}
+
+aoc_common::sample_tests!(Day3);
+
+#[cfg(test)]
+mod day3_tests {
+    use super::*;
+
+    fn parse_raw_instructions(raw: &str) -> Result<Vec<Instruction>, ParseError> {
+        Day3::parse(raw).map(|program| program.instructions().collect())
+    }
+
+    const SAMPLE1: &str = include_str!("../samples/sample1.txt");
+    const SAMPLE2: &str = include_str!("../samples/sample2.txt");
+
+    #[test]
+    fn test_parse_sample_1() {
+        let parsed = parse_raw_instructions(SAMPLE1).expect("could not parse sample");
+        let expected = vec![
+            Instruction::Mul(2, 4),
+            Instruction::Mul(5, 5),
+            Instruction::Mul(11, 8),
+            Instruction::Mul(8, 5),
+        ];
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn test_parse_sample_2() {
+        let parsed = parse_raw_instructions(SAMPLE2).expect("could not parse sample");
+        let expected = vec![
+            Instruction::Mul(2, 4),
+            Instruction::Dont,
+            Instruction::Mul(5, 5),
+            Instruction::Mul(11, 8),
+            Instruction::Do,
+            Instruction::Mul(8, 5),
+        ];
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn test_parse_skips_mul_without_operands() {
+        let parsed = parse_raw_instructions("mul()do()mul(3,4)").expect("could not parse");
+
+        assert_eq!(parsed, vec![Instruction::Do, Instruction::Mul(3, 4)]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let program = Day3::parse("mul(2,3)mul(4*don't()").expect("could not parse");
+        let mut trace = String::new();
+        program
+            .write_trace(&mut trace)
+            .expect("could not write trace");
+
+        assert_eq!(
+            trace.lines().skip(1).collect::<Vec<_>>(),
+            vec![
+                "         0..8  mul(2,3)         true            6",
+                "        8..14  \"mul(4*\"         rejected: expected ',', found '*'",
+                "       14..21  don't()          false           6",
+                "2 instructions, 1 near-misses",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_near_misses() {
+        let program = Day3::parse(include_str!("../samples/tricky.txt")).expect("could not parse");
+        let misses = program
+            .near_misses()
+            .map(|miss| (miss.opcode, miss.span))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            misses,
+            vec![
+                ("mul", 0..8),
+                ("mul", 11..17),
+                ("do", 25..28),
+                ("do", 56..60),
+                ("mul", 73..80),
+            ]
+        );
+        assert_eq!(program.tokens().len(), 7);
+    }
+}
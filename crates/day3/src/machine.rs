@@ -0,0 +1,97 @@
+//! Interpreter running the instructions found in corrupted memory.
+use crate::Instruction;
+
+/// State of the machine as it runs instructions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    /// Whether `mul` instructions currently count towards the accumulator
+    pub enabled: bool,
+    /// Sum of the products of every `mul` counted so far
+    pub accumulator: u32,
+    /// Whether `do()` and `don't()` switch `mul` on and off, or are ignored
+    conditionals: bool,
+}
+
+impl Machine {
+    /// A machine following `do()` and `don't()`
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            accumulator: 0,
+            conditionals: true,
+        }
+    }
+
+    /// A machine ignoring `do()` and `don't()`, so that every `mul` counts
+    pub fn without_conditionals() -> Self {
+        Self {
+            conditionals: false,
+            ..Self::new()
+        }
+    }
+
+    /// Run a single instruction, returning the amount added to the accumulator
+    pub fn step(&mut self, instruction: Instruction) -> u32 {
+        let added = match instruction {
+            Instruction::Mul(a, b) if self.enabled => a * b,
+            Instruction::Mul(..) => 0,
+            Instruction::Do => {
+                self.enabled |= self.conditionals;
+                0
+            }
+            Instruction::Dont => {
+                self.enabled &= !self.conditionals;
+                0
+            }
+        };
+        self.accumulator += added;
+        added
+    }
+
+    /// Run every instruction in turn, returning the final accumulator
+    pub fn run(&mut self, instructions: impl IntoIterator<Item = Instruction>) -> u32 {
+        for instruction in instructions {
+            self.step(instruction);
+        }
+        self.accumulator
+    }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod machine_tests {
+    use super::*;
+
+    const PROGRAM: [Instruction; 4] = [
+        Instruction::Mul(2, 4),
+        Instruction::Dont,
+        Instruction::Mul(5, 5),
+        Instruction::Do,
+    ];
+
+    #[test]
+    fn test_step() {
+        let mut machine = Machine::new();
+        assert_eq!(machine.step(Instruction::Mul(2, 4)), 8);
+        assert_eq!(machine.step(Instruction::Dont), 0);
+        assert!(!machine.enabled);
+        assert_eq!(machine.step(Instruction::Mul(5, 5)), 0);
+        assert_eq!(machine.step(Instruction::Do), 0);
+        assert!(machine.enabled);
+        assert_eq!(machine.accumulator, 8);
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(Machine::new().run(PROGRAM), 8);
+
+        let mut machine = Machine::without_conditionals();
+        assert_eq!(machine.run(PROGRAM), 33);
+        assert!(machine.enabled);
+    }
+}
@@ -0,0 +1,272 @@
+use aoc_common::{
+    geometry::{Coord, Direction8},
+    grid::Grid,
+    ParseError, Solution,
+};
+
+/// Word counted in part 1
+const XMAS: &str = "XMAS";
+
+/// Two diagonal "MAS"es crossing at their "A", counted in part 2 in every rotation
+const X_MAS: &str = "M.S\n.A.\nM.S";
+
+/// Grid of letters to search
+///
+/// Searches only ever borrow the puzzle, so it is deliberately not `Clone`.
+#[derive(PartialEq, Debug, Eq)]
+pub struct Puzzle {
+    letters: Grid<char>,
+}
+
+impl Puzzle {
+    /// Width and height of the puzzle
+    pub fn bounds(&self) -> (usize, usize) {
+        (self.letters.width(), self.letters.height())
+    }
+
+    fn get(&self, k: Coord) -> Option<char> {
+        self.letters.get(k).copied()
+    }
+
+    /// Whether the word is spelled out starting at `start` and reading in the given direction
+    pub fn spells(&self, word: &str, start: Coord, dir: Direction8) -> bool {
+        let mut letters = self.letters.ray(start, dir.delta()).map(|(_, &c)| c);
+        word.chars().all(|c| letters.next() == Some(c))
+    }
+
+    /// Start and direction of every occurrence of the word, in row-major order of their
+    /// starts, then clockwise from up
+    ///
+    /// A single letter reads the same in every direction, so is only found reading up.
+    pub fn find_word<'a>(
+        &'a self,
+        word: &'a str,
+    ) -> impl Iterator<Item = (Coord, Direction8)> + 'a {
+        let directions = match word.chars().count() {
+            0 => &[][..],
+            1 => &Direction8::ALL[..1],
+            _ => &Direction8::ALL[..],
+        };
+        self.letters.coords().flat_map(move |start| {
+            directions
+                .iter()
+                .filter(move |&&dir| self.spells(word, start, dir))
+                .map(move |&dir| (start, dir))
+        })
+    }
+
+    /// Top-left corner of every placement of the pattern, as given, in row-major order
+    pub fn find_pattern<'a>(&'a self, pattern: &'a Pattern) -> impl Iterator<Item = Coord> + 'a {
+        self.letters
+            .coords()
+            .filter(move |&top_left| pattern.matches_at(self, top_left))
+    }
+}
+
+/// Small grid of letters to look for in the puzzle, where `.` matches any letter
+#[derive(Clone, PartialEq, Debug, Eq)]
+pub struct Pattern {
+    cells: Grid<Option<char>>,
+}
+
+impl Pattern {
+    pub const WILDCARD: char = '.';
+
+    pub fn parse(raw: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            cells: Grid::parse(raw, "a letter or '.'", |c| match c {
+                Self::WILDCARD => Some(None),
+                c if c.is_alphabetic() => Some(Some(c)),
+                _ => None,
+            })?,
+        })
+    }
+
+    /// Every distinct quarter-turn rotation of the pattern, starting with the pattern itself
+    pub fn rotations(&self) -> Vec<Pattern> {
+        let mut rotations = vec![self.clone()];
+        for _ in 1..4 {
+            let next = Self {
+                cells: rotations[rotations.len() - 1].cells.rotate_right(),
+            };
+            if !rotations.contains(&next) {
+                rotations.push(next);
+            }
+        }
+        rotations
+    }
+
+    /// Whether the pattern fits in the puzzle with its top-left corner at `top_left`
+    fn matches_at(&self, puzzle: &Puzzle, top_left: Coord) -> bool {
+        self.cells.iter().all(|(offset, expected)| {
+            match (puzzle.get(top_left + offset), expected) {
+                (None, _) => false,
+                (Some(_), None) => true,
+                (Some(found), &Some(expected)) => found == expected,
+            }
+        })
+    }
+}
+
+fn parse_input(raw: &str) -> Result<Puzzle, ParseError> {
+    Ok(Puzzle {
+        letters: Grid::parse(raw, "a letter", |c| c.is_alphabetic().then_some(c))?,
+    })
+}
+
+fn part1(puzzle: &Puzzle) -> usize {
+    puzzle.find_word(XMAS).count()
+}
+
+fn part2(puzzle: &Puzzle) -> usize {
+    let pattern = Pattern::parse(X_MAS).expect("X-MAS pattern should be valid");
+    pattern
+        .rotations()
+        .iter()
+        .map(|rotation| puzzle.find_pattern(rotation).count())
+        .sum()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Puzzle;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+aoc_common::sample_tests!(Day4);
+
+#[cfg(test)]
+mod day4_tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
+
+    #[test]
+    fn test_parse() {
+        let parsed = parse_input(SAMPLE).expect("could not parse sample");
+
+        // arbitrary sample
+        assert_eq!(parsed.get(Coord::new(2, 2)), Some('X'));
+        assert_eq!(parsed.get(Coord::new(0, 2)), Some('M'));
+        assert_eq!(parsed.get(Coord::new(2, 0)), Some('A'));
+        assert_eq!(parsed.get(Coord::new(9, 9)), Some('X'));
+        assert_eq!(parsed.get(Coord::new(10, 0)), None); // out of bounds
+        assert_eq!(parsed.letters.coords().count(), 100);
+        assert_eq!(parsed.bounds(), (10, 10));
+    }
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        let err = parse_input("XMAS\nXMA\nXMAS").expect_err("rows should be ragged");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 1, "XMA"));
+    }
+
+    #[test]
+    fn test_find_word() {
+        let puzzle = parse_input(SAMPLE).expect("could not parse sample");
+        let found = puzzle.find_word(XMAS).collect::<Vec<_>>();
+
+        assert_eq!(found.len(), 18);
+        assert_eq!(found[0], (Coord::new(0, 4), Direction8::DownRight));
+        assert!(found.contains(&(Coord::new(9, 9), Direction8::UpLeft)));
+        assert_eq!(puzzle.find_word("SAMX").count(), found.len());
+    }
+
+    #[test_case::test_case("" => 0 ; "empty")]
+    #[test_case::test_case("X" => 19 ; "single letter")]
+    #[test_case::test_case("XX" => 24 ; "palindrome")]
+    #[test_case::test_case("XMASXMASXMASX" => 0 ; "longer than the grid")]
+    fn test_find_word_count(word: &str) -> usize {
+        parse_input(SAMPLE)
+            .expect("could not parse sample")
+            .find_word(word)
+            .count()
+    }
+
+    #[test]
+    fn test_pattern_rotations() {
+        let pattern = Pattern::parse(X_MAS).expect("could not parse pattern");
+        let rotations = pattern
+            .rotations()
+            .iter()
+            .map(|rotation| {
+                rotation
+                    .cells
+                    .map(|c| c.unwrap_or(Pattern::WILDCARD))
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rotations,
+            vec![
+                "M.S\n.A.\nM.S",
+                "M.M\n.A.\nS.S",
+                "S.M\n.A.\nS.M",
+                "S.S\n.A.\nM.M"
+            ]
+        );
+        let symmetric = Pattern::parse("A.A\n.A.\nA.A").expect("could not parse pattern");
+        assert_eq!(symmetric.rotations().len(), 1);
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let puzzle = parse_input("MXSM\nXAXA\nMXSM").expect("could not parse");
+        let pattern = Pattern::parse(X_MAS).expect("could not parse pattern");
+        assert_eq!(
+            puzzle.find_pattern(&pattern).collect::<Vec<_>>(),
+            vec![Coord::new(0, 0)]
+        );
+        let vertical = Pattern::parse("M\nA").expect("could not parse pattern");
+        assert_eq!(
+            puzzle.find_pattern(&vertical).collect::<Vec<_>>(),
+            vec![Coord::new(0, 3)]
+        );
+    }
+
+    #[test]
+    fn test_pattern_parse_error() {
+        let err = Pattern::parse("M.S\n.A?").expect_err("? is not a letter or wildcard");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 3, "?"));
+    }
+
+    #[test]
+    fn test_part1_large_grid() {
+        // every row reads XMAS left to right, so each X starts one word to the right, and
+        // one down-right and one up-right unless that runs off the top or bottom
+        let row = XMAS.repeat(35);
+        let raw = vec![row.as_str(); 140].join("\n");
+        let puzzle = parse_input(&raw).expect("could not parse grid");
+
+        assert_eq!(puzzle.bounds(), (140, 140));
+        assert_eq!(part1(&puzzle), 140 * 35 + 2 * 137 * 35);
+    }
+
+    #[test_case::test_case("M.S\n.A.\nM.S" => 1 ; "filling the grid")]
+    #[test_case::test_case("MS\nAA" => 0 ; "smaller than the pattern")]
+    #[test_case::test_case("A" => 0 ; "single letter")]
+    fn test_part2_edges(raw: &str) -> usize {
+        // a pattern hanging off the grid is no match, rather than a panic
+        part2(&parse_input(&raw.replace('.', "X")).expect("could not parse grid"))
+    }
+
+    #[test]
+    fn test_contains() {
+        dbg!(&['M', 'S'].contains(&'S'));
+    }
+}
@@ -0,0 +1,53 @@
+//! Write the day 5 rule graph as Graphviz DOT, optionally highlighting the rules one update
+//! breaks.
+//!
+//! ```sh
+//! cargo run -p day5 --example rules_dot -- <input> [update number] [--restrict] | dot -Tsvg > rules.svg
+//! ```
+//!
+//! Updates are numbered from 1 in the order they appear in the input, and `-` reads the input
+//! from stdin. With `--restrict`, only the pages of the update and the rules between them
+//! are drawn.
+use std::{error::Error, path::PathBuf};
+
+use aoc_common::{
+    input::{self, InputSource},
+    Solution,
+};
+use day5::Day5;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let restrict = args.iter().any(|arg| arg == "--restrict");
+    args.retain(|arg| arg != "--restrict");
+
+    let (source, update) = match args.as_slice() {
+        [path] => (path, None),
+        [path, update] => (path, Some(update.parse::<usize>()?)),
+        _ => return Err("usage: rules_dot <input> [update number] [--restrict]".into()),
+    };
+    let source = match source.as_str() {
+        "-" => InputSource::Stdin,
+        path => InputSource::File(PathBuf::from(path)),
+    };
+    let (rules, updates) = Day5::parse(&input::load(Day5::DAY, &source)?)?;
+
+    let dot = rules.dot();
+    let dot = match update {
+        None if restrict => return Err("--restrict needs an update number".into()),
+        None => dot,
+        Some(n) => {
+            let update = n
+                .checked_sub(1)
+                .and_then(|idx| updates.get(idx))
+                .ok_or_else(|| format!("no update {n}; there are {}", updates.len()))?;
+            if restrict {
+                dot.restrict(update)
+            } else {
+                dot.highlight(update)
+            }
+        }
+    };
+    println!("{dot}");
+    Ok(())
+}
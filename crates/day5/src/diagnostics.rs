@@ -0,0 +1,216 @@
+//! Diagnostics for rule sets that don't order an update in exactly one way.
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{Page, Rule, RuleSet, Update};
+
+/// Rules each saying a page must come before the next, with the last page needing to come
+/// before the first, so that no order can satisfy them all
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Pages around the cycle, starting with the smallest
+    pub pages: Vec<Page>,
+    /// Line of the rule between each page and the next, wrapping around to the first
+    pub lines: Vec<usize>,
+}
+
+impl Cycle {
+    /// Cycle through the pages in the given order, looking up the rule between each
+    fn new(rules: &RuleSet, mut pages: Vec<Page>) -> Self {
+        let smallest = (0..pages.len()).min_by_key(|&i| pages[i]).unwrap_or(0);
+        pages.rotate_left(smallest);
+        let lines = (0..pages.len())
+            .map(|i| {
+                rules
+                    .line(pages[i], pages[(i + 1) % pages.len()])
+                    .expect("cycle should only follow rules")
+            })
+            .collect();
+        Self { pages, lines }
+    }
+}
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = self
+            .lines
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "rules on lines {lines} form a cycle: ")?;
+        for page in &self.pages {
+            write!(f, "{page} -> ")?;
+        }
+        write!(f, "{}", self.pages[0])
+    }
+}
+
+/// Reason the rules don't order an update in exactly one way
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// The rules between pages of the update contradict each other
+    Cycle(Cycle),
+    /// No rule orders two pages that could otherwise be next to each other, so either could
+    /// come first
+    Ambiguous(Page, Page),
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cycle(cycle) => write!(f, "{cycle}"),
+            Self::Ambiguous(a, b) => {
+                write!(f, "no rule orders {a} and {b}, so either could come first")
+            }
+        }
+    }
+}
+
+impl RuleSet {
+    /// Pages of the update in an order satisfying every rule between them, or a cycle of
+    /// rules that no order can satisfy
+    ///
+    /// Where the rules allow more than one order, pages keep their order in the update.
+    pub fn topological_order(&self, update: &[Page]) -> Result<Update, Cycle> {
+        let k = update.len();
+        let mut preceding = vec![0; k];
+        for (i, &page) in update.iter().enumerate() {
+            preceding[i] = update
+                .iter()
+                .filter(|&&other| self.must_precede(other, page))
+                .count();
+        }
+
+        let mut placed = vec![false; k];
+        let mut order = Vec::with_capacity(k);
+        while let Some(i) = (0..k).find(|&i| !placed[i] && preceding[i] == 0) {
+            placed[i] = true;
+            order.push(update[i]);
+            for j in 0..k {
+                if self.must_precede(update[i], update[j]) {
+                    preceding[j] -= 1;
+                }
+            }
+        }
+        if order.len() == k {
+            return Ok(order);
+        }
+
+        // every page left unplaced has an unplaced page that must precede it, so walking
+        // backwards through those must eventually come round in a cycle
+        let mut path = vec![(0..k).find(|&i| !placed[i]).expect("a page is unplaced")];
+        loop {
+            let current = update[path[path.len() - 1]];
+            let previous = (0..k)
+                .find(|&i| !placed[i] && self.must_precede(update[i], current))
+                .expect("an unplaced page should have an unplaced predecessor");
+            if let Some(start) = path.iter().position(|&i| i == previous) {
+                let pages = path[start..].iter().rev().map(|&i| update[i]).collect();
+                return Err(Cycle::new(self, pages));
+            }
+            path.push(previous);
+        }
+    }
+
+    /// Everything stopping the rules from ordering the update in exactly one way
+    ///
+    /// A cycle hides any ambiguity, so is reported on its own.
+    pub fn diagnose(&self, update: &[Page]) -> Vec<Diagnostic> {
+        match self.topological_order(update) {
+            Err(cycle) => vec![Diagnostic::Cycle(cycle)],
+            // the order is the only one exactly when a rule links each page to the next
+            Ok(order) => order
+                .windows(2)
+                .filter(|pair| !self.must_precede(pair[0], pair[1]))
+                .map(|pair| Diagnostic::Ambiguous(pair[0], pair[1]))
+                .collect(),
+        }
+    }
+
+    /// Line and rule of every rule about a page that appears in none of the updates
+    pub fn unused_rules(&self, updates: &[Update]) -> Vec<(usize, Rule)> {
+        let pages = updates.iter().flatten().collect::<HashSet<_>>();
+        self.rules
+            .iter()
+            .filter(|(before, after)| !pages.contains(before) || !pages.contains(after))
+            .map(|&(before, after)| {
+                let line = self.line(before, after).expect("rule should be indexed");
+                (line, (before, after))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod diagnostics_tests {
+    use super::*;
+
+    const CYCLIC: &str = "1|2\n2|3\n3|4\n4|2\n1|4";
+
+    fn rules(raw: &str) -> RuleSet {
+        let (rules, _) = crate::parse_input(raw).expect("could not parse rules");
+        rules
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let rules = rules("3|1\n1|2\n3|2");
+        assert_eq!(rules.topological_order(&[1, 2, 3]), Ok(vec![3, 1, 2]));
+        assert_eq!(rules.topological_order(&[2, 5, 1]), Ok(vec![5, 1, 2]));
+        assert_eq!(rules.topological_order(&[]), Ok(vec![]));
+    }
+
+    #[test_case::test_case(&[1, 2, 3, 4] => (vec![2, 3, 4], vec![2, 3, 4]) ; "whole update")]
+    #[test_case::test_case(&[4, 3, 2] => (vec![2, 3, 4], vec![2, 3, 4]) ; "out of order")]
+    #[test_case::test_case(&[5, 4, 2, 3, 1] => (vec![2, 3, 4], vec![2, 3, 4]) ; "with extra pages")]
+    fn test_cycle(update: &[Page]) -> (Vec<Page>, Vec<usize>) {
+        let cycle = rules(CYCLIC)
+            .topological_order(update)
+            .expect_err("rules should be cyclic");
+        (cycle.pages, cycle.lines)
+    }
+
+    #[test]
+    fn test_cycle_outside_update() {
+        // the cycle needs page 3, so an update without it can still be ordered
+        assert_eq!(
+            rules(CYCLIC).topological_order(&[4, 2, 1]),
+            Ok(vec![1, 4, 2])
+        );
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let rules = rules(CYCLIC);
+        assert_eq!(
+            rules
+                .diagnose(&[3, 2, 4])
+                .iter()
+                .map(Diagnostic::to_string)
+                .collect::<Vec<_>>(),
+            vec!["rules on lines 2, 3, 4 form a cycle: 2 -> 3 -> 4 -> 2"]
+        );
+        assert_eq!(
+            rules.diagnose(&[1, 2, 9]),
+            vec![Diagnostic::Ambiguous(2, 9)]
+        );
+        assert_eq!(rules.diagnose(&[4, 1]), vec![]);
+    }
+
+    #[test]
+    fn test_diagnose_sample() {
+        let (rules, updates) = crate::parse_input(include_str!("../samples/sample.txt"))
+            .expect("could not parse sample");
+        for update in &updates {
+            assert_eq!(rules.diagnose(update), vec![]);
+        }
+        assert_eq!(rules.unused_rules(&updates), vec![]);
+    }
+
+    #[test]
+    fn test_unused_rules() {
+        let (rules, updates) =
+            crate::parse_input("1|2\n2|3\n3|4\n\n1,2\n2,4").expect("could not parse input");
+        assert_eq!(rules.unused_rules(&updates), vec![(2, (2, 3)), (3, (3, 4))]);
+    }
+}
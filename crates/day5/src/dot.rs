@@ -0,0 +1,129 @@
+//! Export of the page-ordering rules as a Graphviz DOT graph.
+use std::fmt::Display;
+
+use crate::{Page, RuleSet};
+
+/// Rule graph in Graphviz DOT format, with an edge from each page to every page a rule says
+/// must come after it
+///
+/// Given an update, the pages it contains are filled in and the rules it breaks are drawn
+/// in red, so that rendering the graph shows why the update was rejected.
+#[derive(Debug, Clone, Copy)]
+pub struct Dot<'a> {
+    rules: &'a RuleSet,
+    update: Option<&'a [Page]>,
+    restricted: bool,
+}
+
+impl<'a> Dot<'a> {
+    /// Highlight the pages of the update and the rules it breaks
+    pub fn highlight(self, update: &'a [Page]) -> Self {
+        Self {
+            update: Some(update),
+            ..self
+        }
+    }
+
+    /// Only draw the pages of the update and the rules between them, highlighting the
+    /// rules it breaks
+    pub fn restrict(self, update: &'a [Page]) -> Self {
+        Self {
+            restricted: true,
+            ..self.highlight(update)
+        }
+    }
+
+    fn position(&self, page: Page) -> Option<usize> {
+        self.update?.iter().position(|&p| p == page)
+    }
+}
+
+impl Display for Dot<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "digraph rules {{")?;
+        for (idx, page) in self.update.unwrap_or_default().iter().enumerate() {
+            writeln!(f, "    {page} [style=filled, xlabel=\"#{}\"];", idx + 1)?;
+        }
+        for &(before, after) in self.rules.rules() {
+            let line = self
+                .rules
+                .line(before, after)
+                .expect("rule should be indexed");
+            let positions = (self.position(before), self.position(after));
+            if self.restricted && (positions.0.is_none() || positions.1.is_none()) {
+                continue;
+            }
+            let broken = matches!(positions, (Some(b), Some(a)) if b > a);
+            write!(f, "    {before} -> {after} [label=\"line {line}\"")?;
+            if broken {
+                write!(f, ", color=red, fontcolor=red, penwidth=2")?;
+            }
+            writeln!(f, "];")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl RuleSet {
+    /// Every rule as a DOT graph, to be highlighted or restricted to an update if needed
+    pub fn dot(&self) -> Dot<'_> {
+        Dot {
+            rules: self,
+            update: None,
+            restricted: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod dot_tests {
+    use super::*;
+
+    const RULES: &str = "1|2\n2|3\n3|4\n5|1";
+
+    fn rules() -> RuleSet {
+        let (rules, _) = crate::parse_input(RULES).expect("could not parse rules");
+        rules
+    }
+
+    #[test]
+    fn test_whole_graph() {
+        assert_eq!(
+            rules().dot().to_string(),
+            "digraph rules {
+    1 -> 2 [label=\"line 1\"];
+    2 -> 3 [label=\"line 2\"];
+    3 -> 4 [label=\"line 3\"];
+    5 -> 1 [label=\"line 4\"];
+}"
+        );
+    }
+
+    #[test]
+    fn test_highlight() {
+        let rules = rules();
+        let dot = rules.dot().highlight(&[3, 2, 4]).to_string();
+
+        assert!(dot.contains("    3 [style=filled, xlabel=\"#1\"];\n"));
+        assert!(
+            dot.contains("    2 -> 3 [label=\"line 2\", color=red, fontcolor=red, penwidth=2];\n")
+        );
+        assert!(dot.contains("    3 -> 4 [label=\"line 3\"];\n"));
+        assert!(dot.contains("    5 -> 1 [label=\"line 4\"];\n"));
+    }
+
+    #[test]
+    fn test_restrict() {
+        let rules = rules();
+        let dot = rules.dot().restrict(&[2, 1]).to_string();
+
+        assert_eq!(
+            dot,
+            "digraph rules {
+    2 [style=filled, xlabel=\"#1\"];
+    1 [style=filled, xlabel=\"#2\"];
+    1 -> 2 [label=\"line 1\", color=red, fontcolor=red, penwidth=2];
+}"
+        );
+    }
+}
@@ -0,0 +1,362 @@
+mod diagnostics;
+mod dot;
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use aoc_common::{parse::parse_token, ParseError, Solution};
+
+pub use diagnostics::{Cycle, Diagnostic};
+pub use dot::Dot;
+
+type Rule = (u32, u32);
+type Update = Vec<Page>;
+type Page = u32;
+
+/// Page-ordering rules, indexed by the page each rule says must come first
+///
+/// Built once from the parsed rules, so that checking whether one page must precede
+/// another is a set lookup rather than a scan over every rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+    /// Pages that must come after each page, with the line of the rule saying so
+    successors: HashMap<Page, HashMap<Page, usize>>,
+}
+
+impl RuleSet {
+    /// Rule set given one rule per line, starting on the first line
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self::with_lines(rules.into_iter().zip(1..))
+    }
+
+    /// Rule set from rules along with the line each was given on
+    fn with_lines(rules: impl IntoIterator<Item = (Rule, usize)>) -> Self {
+        let mut set = Self {
+            rules: vec![],
+            successors: HashMap::new(),
+        };
+        for ((before, after), line) in rules {
+            set.rules.push((before, after));
+            set.successors
+                .entry(before)
+                .or_default()
+                .entry(after)
+                .or_insert(line);
+        }
+        set
+    }
+
+    /// Rules in the order they were given
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Whether a rule says `before` must be printed before `after`
+    pub fn must_precede(&self, before: Page, after: Page) -> bool {
+        self.line(before, after).is_some()
+    }
+
+    /// Line of the first rule saying `before` must be printed before `after`, if any
+    pub fn line(&self, before: Page, after: Page) -> Option<usize> {
+        self.successors
+            .get(&before)
+            .and_then(|pages| pages.get(&after))
+            .copied()
+    }
+
+    /// Comparator for pages of the update, following rules between its pages transitively
+    ///
+    /// Pages are ranked by how many pages of the update must come before them, directly or
+    /// through other pages of the update, so a page always sorts after everything that must
+    /// precede it. Rules about pages outside the update are ignored.
+    pub fn comparator(&self, update: &[Page]) -> impl Fn(&Page, &Page) -> Ordering {
+        let preceding = update
+            .iter()
+            .map(|&page| {
+                let mut found = HashSet::new();
+                let mut stack = vec![page];
+                while let Some(current) = stack.pop() {
+                    for &other in update {
+                        if self.must_precede(other, current) && found.insert(other) {
+                            stack.push(other);
+                        }
+                    }
+                }
+                (page, found.len())
+            })
+            .collect::<HashMap<_, _>>();
+        move |a, b| preceding.get(a).cmp(&preceding.get(b))
+    }
+
+    /// Whether no later page in the update must come before an earlier one
+    pub fn is_ordered(&self, update: &[Page]) -> bool {
+        update.iter().enumerate().all(|(i, &page)| {
+            update[i + 1..]
+                .iter()
+                .all(|&later| !self.must_precede(later, page))
+        })
+    }
+
+    /// Copy of the update sorted with [`RuleSet::comparator`]
+    ///
+    /// The result satisfies every rule between pages of the update unless those rules form a
+    /// cycle, which [`RuleSet::diagnose`] can report.
+    pub fn sorted(&self, update: &[Page]) -> Update {
+        let mut sorted = update.to_vec();
+        sorted.sort_by(self.comparator(update));
+        sorted
+    }
+
+    /// Graph with an edge for every rule between pages of the update, or every rule if no
+    /// update is given
+    #[cfg(feature = "petgraph")]
+    pub fn graph(&self, update: Option<&[Page]>) -> petgraph::graphmap::DiGraphMap<Page, ()> {
+        self.rules
+            .iter()
+            .filter(|(before, after)| {
+                update.is_none_or(|pages| pages.contains(before) && pages.contains(after))
+            })
+            .map(|&(before, after)| (before, after, ()))
+            .collect()
+    }
+}
+
+fn parse_input(raw: &str) -> Result<(RuleSet, Vec<Update>), ParseError> {
+    let mut rules = vec![];
+    let mut updates = vec![];
+
+    for (l, line) in raw.lines().zip(1..) {
+        if let Some((a, b)) = l.split_once("|") {
+            rules.push((
+                (
+                    parse_token(raw, a, "a page number")?,
+                    parse_token(raw, b, "a page number")?,
+                ),
+                line,
+            ))
+        } else if !l.is_empty() {
+            updates.push(
+                l.split(",")
+                    .map(|c| parse_token(raw, c, "a page number"))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+    }
+
+    Ok((RuleSet::with_lines(rules), updates))
+}
+
+fn middle_page_from_update(update: &[Page]) -> Page {
+    let l = update.len();
+    update[l / 2]
+}
+
+/// Write how each update should be ordered, or why the rules can't say, followed by the
+/// rules about pages that no update contains
+fn write_report(rules: &RuleSet, updates: &[Update], out: &mut impl Write) -> std::fmt::Result {
+    let join = |pages: &[Page]| {
+        pages
+            .iter()
+            .map(Page::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    for update in updates {
+        write!(out, "{}: ", join(update))?;
+        match rules.topological_order(update) {
+            Err(_) => writeln!(out, "cannot be ordered")?,
+            Ok(_) if rules.is_ordered(update) => writeln!(out, "in order")?,
+            Ok(order) => writeln!(out, "should be {}", join(&order))?,
+        }
+        for diagnostic in rules.diagnose(update) {
+            writeln!(out, "  {diagnostic}")?;
+        }
+    }
+    for (line, (before, after)) in rules.unused_rules(updates) {
+        writeln!(
+            out,
+            "rule {before}|{after} on line {line} is about a page in no update"
+        )?;
+    }
+    Ok(())
+}
+
+fn part1(rules: &RuleSet, updates: &[Update]) -> u32 {
+    updates
+        .iter()
+        .filter(|&u| rules.is_ordered(u))
+        .map(|u| middle_page_from_update(u))
+        .sum()
+}
+
+/// Sum of the middle pages of the reordered updates
+///
+/// Updates whose rules form a cycle have no correct order, so they are left out of the sum
+/// rather than guessed at; `aoc explain` reports the cycle.
+fn part2(rules: &RuleSet, updates: &[Update]) -> u32 {
+    updates
+        .iter()
+        .filter(|&u| !rules.is_ordered(u))
+        .filter_map(|u| {
+            let sorted = rules.sorted(u);
+            rules
+                .is_ordered(&sorted)
+                .then(|| middle_page_from_update(&sorted))
+        })
+        .sum()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = (RuleSet, Vec<Update>);
+    type Answer = u32;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1((rules, updates): &Self::Input) -> u32 {
+        part1(rules, updates)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> u32 {
+        part2(rules, updates)
+    }
+
+    /// Say how each update should be ordered, or why the rules can't say, and which rules
+    /// are about pages that no update contains
+    fn explain((rules, updates): &Self::Input) -> Option<String> {
+        let mut out = String::new();
+        write_report(rules, updates, &mut out).expect("could not write report");
+        Some(out)
+    }
+}
+
+aoc_common::sample_tests!(Day5);
+
+#[cfg(test)]
+mod day5_tests {
+    use super::*;
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
+
+    #[test]
+    fn test_parse_sample() {
+        let (rules, updates) = parse_input(SAMPLE).expect("could not parse sample");
+
+        assert_eq!(rules.rules().len(), 21);
+        assert_eq!(rules.rules()[3], (97, 47));
+        assert_eq!(updates.len(), 6);
+        assert_eq!(updates[3], vec![75, 97, 47, 61, 53])
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("47|53\n97|x\n\n75,47").expect_err("rule should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 4, "x"));
+
+        let err = parse_input("47|53\n\n75,47,").expect_err("update should be truncated");
+        assert_eq!((err.line, err.column, err.token.as_str()), (3, 7, ""));
+    }
+
+    #[test]
+    fn test_middle_page_from_update() {
+        let u: Update = vec![4, 6, 2, 8, 7];
+
+        assert_eq!(middle_page_from_update(&u), 2);
+    }
+
+    #[test]
+    fn test_satisfied_rule() {
+        let rules = RuleSet::new(vec![(97, 75)]);
+        let update = vec![75, 97, 47, 61, 53];
+
+        assert!(!rules.is_ordered(&update));
+        assert!(rules.is_ordered(&[97, 47, 75]));
+        assert!(rules.is_ordered(&[47, 61]), "unrelated pages are in order");
+    }
+
+    #[test]
+    fn test_compare() {
+        let (rules, _) = parse_input(SAMPLE).expect("could not parse sample");
+
+        assert!(rules.must_precede(97, 75));
+        assert!(!rules.must_precede(75, 97));
+        let compare = rules.comparator(&[75, 97, 47]);
+        assert_eq!(compare(&97, &75), Ordering::Less);
+        assert_eq!(compare(&75, &97), Ordering::Greater);
+        assert_eq!(compare(&75, &75), Ordering::Equal);
+        assert_eq!(compare(&97, &47), Ordering::Less);
+        assert_eq!(rules.line(97, 47), Some(4));
+        assert_eq!(rules.line(47, 97), None);
+    }
+
+    #[test]
+    fn test_reorder_pages_from_update() {
+        let (rules, updates) = parse_input(SAMPLE).expect("could not parse sample");
+        let update = vec![75, 97, 47, 61, 53];
+
+        assert_eq!(rules.sorted(&update), vec![97, 75, 47, 61, 53]);
+        for update in updates {
+            assert!(rules.is_ordered(&rules.sorted(&update)));
+        }
+    }
+
+    #[test]
+    fn test_reorder_with_transitive_rules() {
+        // no rule relates 3 and 1 directly, only through 2
+        let (rules, updates) = parse_input("1|2\n2|3\n\n3,1,2").expect("could not parse");
+
+        let compare = rules.comparator(&updates[0]);
+        assert_eq!(compare(&3, &1), Ordering::Greater);
+        assert_eq!(rules.sorted(&updates[0]), vec![1, 2, 3]);
+        assert_eq!(part2(&rules, &updates), 2);
+
+        // without page 2 in the update, nothing orders 3 and 1
+        assert_eq!(rules.sorted(&[3, 1]), vec![3, 1]);
+    }
+
+    #[test]
+    fn test_reorder_skips_cycle() {
+        let (rules, updates) =
+            parse_input("1|2\n2|3\n3|1\n4|5\n5|6\n\n3,1,2\n6,5,4").expect("could not parse");
+
+        assert!(!rules.is_ordered(&rules.sorted(&updates[0])));
+        assert_eq!(part2(&rules, &updates), 5);
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[test]
+    fn test_graph() {
+        let (rules, _) = parse_input(SAMPLE).expect("could not parse sample");
+
+        assert_eq!(rules.graph(None).edge_count(), 21);
+        let graph = rules.graph(Some(&[75, 97, 47]));
+        assert_eq!(graph.edge_count(), 3);
+        assert!(graph.contains_edge(97, 75));
+    }
+
+    #[test]
+    fn test_write_report() {
+        let (rules, updates) =
+            parse_input("1|2\n2|3\n3|1\n1|4\n7|8\n\n1,2,3\n4,1,2\n").expect("could not parse");
+        let mut report = String::new();
+        write_report(&rules, &updates, &mut report).expect("could not write report");
+
+        assert_eq!(
+            report.lines().collect::<Vec<_>>(),
+            vec![
+                "1,2,3: cannot be ordered",
+                "  rules on lines 1, 2, 3 form a cycle: 1 -> 2 -> 3 -> 1",
+                "4,1,2: should be 1,4,2",
+                "  no rule orders 4 and 2, so either could come first",
+                "rule 7|8 on line 5 is about a page in no update",
+            ]
+        );
+    }
+}
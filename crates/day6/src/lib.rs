@@ -0,0 +1,329 @@
+// For parellel iterators
+use rayon::prelude::*;
+
+use std::collections::HashSet;
+
+use aoc_common::{
+    geometry::{Coord, Direction4},
+    grid::Grid,
+    ParseError, Solution,
+};
+
+/// Position of the guard and the direction they are facing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub pos: Coord,
+    pub dir: Direction4,
+}
+
+/// How a walk around the lab ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkOutcome {
+    /// The guard walked off the edge of the map
+    Exited,
+    /// The guard came back to a position they had been in facing the same way, so will
+    /// walk the same loop forever
+    Looped,
+}
+
+/// Every state the guard passes through, one step or turn at a time, and how the walk ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub states: Vec<Guard>,
+    pub outcome: WalkOutcome,
+}
+
+impl Route {
+    /// Every cell the guard walks into after the start, paired with their state just before
+    /// walking into it for the first time
+    ///
+    /// An obstacle can only change the route if it is on it, and until the guard first
+    /// reaches it the route is the same, so the walk can pick up from that state.
+    pub fn first_entries(&self) -> Vec<(Coord, Guard)> {
+        let mut seen = HashSet::from([self.states[0].pos]);
+        self.states
+            .windows(2)
+            .filter(|pair| seen.insert(pair[1].pos))
+            .map(|pair| (pair[1].pos, pair[0]))
+            .collect()
+    }
+}
+
+/// Map of the lab, with the guard where they start
+#[derive(Debug, Clone)]
+pub struct Lab {
+    walls: Grid<bool>,
+    start: Guard,
+    /// Where the guard stops when walking from each cell in each direction, just before the
+    /// next wall, or `None` if they would walk off the map; indexed by direction
+    jumps: Grid<[Option<Coord>; 4]>,
+}
+
+impl Lab {
+    fn from_str(raw: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(raw, "`#`, `.` or `^`", |char| {
+            matches!(char, '#' | '.' | '^').then_some(char)
+        })?;
+        let start = map
+            .position(|&c| c == '^')
+            .ok_or_else(|| ParseError::eof(raw, "a guard (`^`) somewhere on the map"))?;
+        let walls = map.map(|&c| c == '#');
+
+        let jumps = Self::jump_table(&walls);
+        Ok(Lab {
+            walls,
+            start: Guard {
+                pos: start,
+                dir: Direction4::Up,
+            },
+            jumps,
+        })
+    }
+
+    /// Stops for every cell and direction, each worked out from the stop of the cell ahead
+    fn jump_table(walls: &Grid<bool>) -> Grid<[Option<Coord>; 4]> {
+        let mut jumps = walls.map(|_| [None; 4]);
+        let coords = walls.coords().collect::<Vec<_>>();
+        for dir in Direction4::ALL {
+            // visit the cell ahead of each cell before the cell itself
+            let ordered: Box<dyn Iterator<Item = &Coord>> = match dir {
+                Direction4::Up | Direction4::Left => Box::new(coords.iter()),
+                Direction4::Down | Direction4::Right => Box::new(coords.iter().rev()),
+            };
+            for &coord in ordered {
+                let ahead = coord.step(dir);
+                jumps[coord][dir as usize] = match walls.get(ahead) {
+                    None => None,
+                    Some(true) => Some(coord),
+                    Some(false) => jumps[ahead][dir as usize],
+                };
+            }
+        }
+        jumps
+    }
+
+    /// Walk the guard from the start one step at a time, recording every state along the way
+    pub fn route(&self) -> Route {
+        let mut guard = self.start;
+        let mut seen = HashSet::new();
+        let mut states = vec![];
+        loop {
+            if !seen.insert(guard) {
+                return Route {
+                    states,
+                    outcome: WalkOutcome::Looped,
+                };
+            }
+            states.push(guard);
+            let ahead = guard.pos.step(guard.dir);
+            match self.walls.get(ahead) {
+                None => {
+                    return Route {
+                        states,
+                        outcome: WalkOutcome::Exited,
+                    }
+                }
+                Some(true) => guard.dir = guard.dir.turn_right(),
+                Some(false) => guard.pos = ahead,
+            }
+        }
+    }
+
+    /// Walk the guard from the given state straight from wall to wall until they leave the
+    /// map or loop, with an extra obstacle placed on the map if given
+    pub fn walk(&self, mut guard: Guard, obstacle: Option<Coord>) -> WalkOutcome {
+        let mut turns = HashSet::new();
+        loop {
+            let stop = self.jumps[guard.pos][guard.dir as usize];
+            let stop = match obstacle {
+                Some(obstacle) if Self::blocks(guard, obstacle, stop) => {
+                    Some(obstacle - guard.dir.delta())
+                }
+                _ => stop,
+            };
+            let Some(pos) = stop else {
+                return WalkOutcome::Exited;
+            };
+            guard.pos = pos;
+            if !turns.insert(guard) {
+                return WalkOutcome::Looped;
+            }
+            guard.dir = guard.dir.turn_right();
+        }
+    }
+
+    /// Whether the obstacle is ahead of the guard, and no further than the wall they would
+    /// otherwise stop at
+    fn blocks(guard: Guard, obstacle: Coord, stop: Option<Coord>) -> bool {
+        let offset = obstacle - guard.pos;
+        let distance = guard.pos.manhattan(obstacle);
+        let ahead = distance > 0 && offset == guard.dir.delta() * distance as i32;
+        ahead && stop.is_none_or(|stop| distance <= guard.pos.manhattan(stop) + 1)
+    }
+}
+
+fn parse_input(raw: &str) -> Result<Lab, ParseError> {
+    Lab::from_str(raw)
+}
+
+fn part1(lab: &Lab) -> usize {
+    lab.route()
+        .states
+        .iter()
+        .map(|guard| guard.pos)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn part2(lab: &Lab) -> usize {
+    lab.route()
+        .first_entries()
+        .into_par_iter()
+        .filter(|&(obstacle, guard)| lab.walk(guard, Some(obstacle)) == WalkOutcome::Looped)
+        .count()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Lab;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+aoc_common::sample_tests!(Day6);
+
+#[cfg(test)]
+mod day6_tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let parsed = parse_input(SAMPLE).expect("could not parse sample");
+        assert!(parsed.walls[Coord::new(3, 2)]);
+        assert!(!parsed.walls[Coord::new(3, 3)]);
+        assert!(parsed.walls[Coord::new(0, 4)]);
+        assert!(!parsed.walls[Coord::new(6, 4)]);
+        assert_eq!(parsed.start.pos, Coord::new(6, 4));
+        assert_eq!(parsed.start.dir, Direction4::Up);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("..#.\n.^.v").expect_err("map should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 4, "v"));
+
+        let err = parse_input("..#.\n....").expect_err("map should have no guard");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 5, ""));
+    }
+
+    #[test]
+    fn test_jump_table() {
+        let lab = parse_input(SAMPLE).expect("could not parse sample");
+        let stops = Direction4::ALL.map(|dir| lab.jumps[lab.start.pos][dir as usize]);
+
+        assert_eq!(
+            stops,
+            [Some(Coord::new(1, 4)), None, None, Some(Coord::new(6, 2)),]
+        );
+        // a wall straight ahead means stopping where you are
+        assert_eq!(
+            lab.jumps[Coord::new(1, 4)][Direction4::Up as usize],
+            Some(Coord::new(1, 4))
+        );
+    }
+
+    #[test]
+    fn test_route() {
+        let lab = parse_input(SAMPLE).expect("could not parse sample");
+        let route = lab.route();
+
+        assert_eq!(route.outcome, WalkOutcome::Exited);
+        assert_eq!(route.states[0], lab.start);
+        assert_eq!(
+            route.states[6],
+            Guard {
+                pos: Coord::new(1, 4),
+                dir: Direction4::Right,
+            }
+        );
+        assert_eq!(route.states.last().map(|g| g.pos), Some(Coord::new(9, 7)));
+    }
+
+    #[test_case::test_case(None => WalkOutcome::Exited ; "no obstacle")]
+    #[test_case::test_case(Some(Coord::new(6, 3)) => WalkOutcome::Looped ; "beside the start")]
+    #[test_case::test_case(Some(Coord::new(7, 7)) => WalkOutcome::Looped ; "near the exit")]
+    #[test_case::test_case(Some(Coord::new(0, 0)) => WalkOutcome::Exited ; "off the path")]
+    fn test_walk(obstacle: Option<Coord>) -> WalkOutcome {
+        let lab = parse_input(SAMPLE).expect("could not parse sample");
+        lab.walk(lab.start, obstacle)
+    }
+
+    #[test]
+    fn test_loop_without_obstacle() {
+        let lab = parse_input(".#..\n...#\n#^..\n..#.").expect("could not parse map");
+
+        assert_eq!(lab.route().outcome, WalkOutcome::Looped);
+        assert_eq!(lab.walk(lab.start, None), WalkOutcome::Looped);
+        assert_eq!(part1(&lab), 4);
+    }
+
+    #[test]
+    fn test_first_entries() {
+        let lab = parse_input(SAMPLE).expect("could not parse sample");
+        let entries = lab.route().first_entries();
+
+        assert_eq!(entries.len(), part1(&lab) - 1);
+        assert_eq!(entries[0], (Coord::new(5, 4), lab.start));
+        for (obstacle, guard) in entries {
+            assert_eq!(guard.pos.step(guard.dir), obstacle);
+        }
+    }
+
+    #[test]
+    fn test_part2_matches_every_cell() {
+        // obstacles off the route can't make the guard loop
+        let lab = parse_input(SAMPLE).expect("could not parse sample");
+        let every_cell = lab
+            .walls
+            .iter()
+            .filter(|&(c, &is_wall)| !is_wall && c != lab.start.pos)
+            .filter(|&(c, _)| lab.walk(lab.start, Some(c)) == WalkOutcome::Looped)
+            .count();
+
+        assert_eq!(part2(&lab), every_cell);
+    }
+
+    #[test]
+    fn test_walk_matches_route() {
+        // with no extra obstacle, jumping between walls ends the same way as stepping
+        let lab = parse_input(SAMPLE).expect("could not parse sample");
+        for (coord, &is_wall) in lab.walls.iter() {
+            if is_wall || coord == lab.start.pos {
+                continue;
+            }
+            let mut blocked = lab.clone();
+            blocked.walls[coord] = true;
+            blocked.jumps = Lab::jump_table(&blocked.walls);
+            assert_eq!(
+                blocked.route().outcome,
+                lab.walk(lab.start, Some(coord)),
+                "obstacle at {coord}"
+            );
+        }
+    }
+}
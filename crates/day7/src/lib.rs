@@ -1,11 +1,11 @@
 use std::iter::zip;
 
+use aoc_common::{parse::parse_token, ParseError, Solution};
 use itertools::{repeat_n, Itertools};
 
-const RAW: &str = include_str!("../input.txt");
 type Target = usize;
 type Operand = usize;
-type Input = Vec<(Target, Vec<Operand>)>;
+pub type Input = Vec<(Target, Vec<Operand>)>;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Operator {
@@ -18,29 +18,32 @@ fn get_combo_iter(item_vals: Vec<Operator>, n_items: usize) -> impl Iterator<Ite
     repeat_n(item_vals, n_items).multi_cartesian_product() // permutations with replacement
 }
 
-fn parse_input(raw: &str) -> Input {
+fn parse_input(raw: &str) -> Result<Input, ParseError> {
     let mut input = Input::new();
 
     for line in raw.lines() {
-        let mut iter = line.split(':');
-        let target = iter
-            .next()
-            .expect("could not get target")
-            .parse::<Target>()
-            .expect("could not parse value");
-        let operands = iter
-            .next()
-            .expect("could not get target")
+        let (target, operands) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(raw, line, "`<target>: <operands>`"))?;
+        let target = parse_token::<Target>(raw, target, "a target value")?;
+        let operands = operands
             .split_whitespace()
-            .map(|s| s.parse::<Operand>().expect("could not parse value"))
-            .collect::<Vec<_>>();
+            .map(|s| parse_token::<Operand>(raw, s, "an operand"))
+            .collect::<Result<Vec<_>, _>>()?;
+        if operands.is_empty() {
+            return Err(ParseError::at(
+                raw,
+                &line[line.len()..],
+                "at least one operand",
+            ));
+        }
         input.push((target, operands));
     }
 
-    input
+    Ok(input)
 }
 
-fn evaluate(operands: &Vec<Operand>, operators: Vec<Operator>) -> usize {
+fn evaluate(operands: &[Operand], operators: Vec<Operator>) -> usize {
     let initial_val = operands[0];
     zip(operands[1..].iter(), operators).fold(
         initial_val,
@@ -52,7 +55,7 @@ fn evaluate(operands: &Vec<Operand>, operators: Vec<Operator>) -> usize {
     )
 }
 
-fn equation_is_solvable(target: Target, operands: &Vec<Operand>, operators: Vec<Operator>) -> bool {
+fn equation_is_solvable(target: Target, operands: &[Operand], operators: Vec<Operator>) -> bool {
     for operators in get_combo_iter(operators, operands.len() - 1) {
         if evaluate(operands, operators) == target {
             return true;
@@ -63,9 +66,9 @@ fn equation_is_solvable(target: Target, operands: &Vec<Operand>, operators: Vec<
 
 fn calibration_result(input: &Input, operators: Vec<Operator>) -> usize {
     input
-        .into_iter()
+        .iter()
         .filter_map(|(target, operands)| {
-            if equation_is_solvable(*target, &operands, operators.clone()) {
+            if equation_is_solvable(*target, operands, operators.clone()) {
                 Some(target)
             } else {
                 None
@@ -85,18 +88,33 @@ fn part2(input: &Input) -> usize {
     )
 }
 
-fn main() {
-    let input = parse_input(RAW);
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
 }
 
+aoc_common::sample_tests!(Day7);
+
 #[cfg(test)]
 mod day7_tests {
     use super::*;
 
-    const SAMPLE: &str = include_str!("../sample.txt");
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
 
     #[test_case::test_case(vec![10, 19], vec![Operator::Add] => 29)]
     #[test_case::test_case(vec![10, 19], vec![Operator::Multiply] => 190)]
@@ -133,24 +151,16 @@ mod day7_tests {
 
     #[test]
     fn test_parse_input() {
-        let input = parse_input(SAMPLE);
+        let input = parse_input(SAMPLE).expect("could not parse sample");
         assert_eq!(input.len(), 9);
         assert_eq!(input[5], (161011, vec![16, 10, 13]))
     }
 
-    #[test]
-    fn test_part1() {
-        let input = parse_input(SAMPLE);
-        let expected = 3749;
-
-        assert_eq!(part1(&input), expected);
-    }
-
-    #[test]
-    fn test_part2() {
-        let input = parse_input(SAMPLE);
-        let expected = 11387;
-
-        assert_eq!(part2(&input), expected);
+    #[test_case::test_case("190: 10 19\n3267 81 40 27" => (2, 1, "3267 81 40 27".to_owned()) ; "missing colon")]
+    #[test_case::test_case("190: 10 19\n3267: 81 4o 27" => (2, 10, "4o".to_owned()) ; "bad operand")]
+    #[test_case::test_case("190: 10 19\n3267:" => (2, 6, "".to_owned()) ; "no operands")]
+    fn test_parse_error(raw: &str) -> (usize, usize, String) {
+        let err = parse_input(raw).expect_err("input should be invalid");
+        (err.line, err.column, err.token)
     }
 }
@@ -1,12 +1,6 @@
-use std::collections::HashMap;
-
+use aoc_common::{geometry::Coord, grid::Grid, ParseError, Solution};
 use itertools::Itertools;
 
-const RAW: &str = include_str!("../input.txt");
-type Row = i32;
-type Col = i32;
-type Coord = (Row, Col);
-
 type AntennaFreq = char;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -22,52 +16,35 @@ struct Location {
 }
 
 #[derive(Debug, Clone)]
-struct Input {
-    map: HashMap<Coord, Location>,
-    bounds: (usize, usize),
+pub struct Input {
+    map: Grid<Location>,
 }
 
 impl Input {
-    fn from_str(raw: &str) -> Self {
-        let mut input = Input {
-            map: HashMap::new(),
-            bounds: (0, 0),
-        };
-        let mut max_row = 0;
-        let mut max_col = 0;
-        for (row, line) in raw.lines().enumerate() {
-            if row > max_row {
-                max_row = row;
-            }
-            for (col, char) in line.chars().enumerate() {
-                if col > max_col {
-                    max_col = col;
-                }
-                let freq = if char == '.' { None } else { Some(char) };
-                input.map.insert(
-                    (row as i32, col as i32),
-                    Location {
-                        freq,
-                        is_antinode: false,
-                    },
-                );
-            }
-        }
-
-        input.bounds = (max_row + 1, max_col + 1);
-        input
+    fn from_str(raw: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(
+            raw,
+            "`.` or an antenna frequency (letter or digit)",
+            |char| {
+                let freq = match char {
+                    '.' => None,
+                    c if c.is_ascii_alphanumeric() => Some(c),
+                    _ => return None,
+                };
+                Some(Location {
+                    freq,
+                    is_antinode: false,
+                })
+            },
+        )?;
+
+        Ok(Input { map })
     }
 
     fn find_antennas(&self, freq: char) -> Vec<Coord> {
         self.map
             .iter()
-            .filter_map(|(c, l)| {
-                if l.freq == Some(freq) {
-                    Some(c.clone())
-                } else {
-                    None
-                }
-            })
+            .filter_map(|(c, l)| if l.freq == Some(freq) { Some(c) } else { None })
             .collect::<Vec<_>>()
     }
 
@@ -75,8 +52,8 @@ impl Input {
         // get unique frequencies found on map
         let unique_freqs = self
             .map
-            .values()
-            .filter_map(|l| l.freq)
+            .iter()
+            .filter_map(|(_, l)| l.freq)
             .unique()
             .collect::<Vec<_>>();
 
@@ -92,14 +69,14 @@ impl Input {
                     let antinodes = antinode_locations(
                         *antenna_combos[0],
                         *antenna_combos[1],
-                        self.bounds,
+                        &self.map,
                         model,
                     );
 
                     // for each antinode location, if on grid, update map location to set status
                     for antinode in antinodes {
                         self.map
-                            .get_mut(&antinode)
+                            .get_mut(antinode)
                             .expect("could not get coord")
                             .is_antinode = true;
                     }
@@ -113,28 +90,17 @@ impl Input {
     }
 }
 
-fn add_coord(c: Coord, diff: Coord, invert: bool) -> Coord {
-    if invert {
-        (c.0 - diff.0, c.1 - diff.1)
-    } else {
-        (c.0 + diff.0, c.1 + diff.1)
-    }
-}
-
-fn antinode_locations(
+fn antinode_locations<T>(
     c1: Coord,
     c2: Coord,
-    bounds: (usize, usize),
+    grid: &Grid<T>,
     model: &AntinodeModel,
 ) -> Vec<Coord> {
-    let diff = (c2.0 - c1.0, c2.1 - c1.1);
+    let diff = c2 - c1;
     let mut antinodes = vec![];
     if model == &AntinodeModel::Old {
-        let antinode1 = add_coord(c2, diff, false);
-        let antinode2 = add_coord(c1, diff, true);
-
-        for n in [antinode1, antinode2] {
-            if coord_in_bound(n, bounds) {
+        for n in [c2 + diff, c1 - diff] {
+            if grid.contains(n) {
                 antinodes.push(n);
             }
         }
@@ -144,25 +110,21 @@ fn antinode_locations(
         antinodes.push(c2);
 
         // Step in both directions until off the grid
-        let mut c = add_coord(c2, diff, false);
-        while coord_in_bound(c, bounds) {
+        let mut c = c2 + diff;
+        while grid.contains(c) {
             antinodes.push(c);
-            c = add_coord(c, diff, false);
+            c += diff;
         }
-        c = add_coord(c1, diff, true);
-        while coord_in_bound(c, bounds) {
+        c = c1 - diff;
+        while grid.contains(c) {
             antinodes.push(c);
-            c = add_coord(c, diff, true)
+            c -= diff;
         }
     }
     antinodes
 }
 
-fn coord_in_bound(c: Coord, bounds: (usize, usize)) -> bool {
-    c.0 >= 0 && c.0 < bounds.0 as i32 && c.1 >= 0 && c.1 < bounds.1 as i32
-}
-
-fn parse_input(raw: &str) -> Input {
+fn parse_input(raw: &str) -> Result<Input, ParseError> {
     Input::from_str(raw)
 }
 
@@ -178,66 +140,67 @@ fn part2(input: &Input) -> usize {
     input.num_antinodes()
 }
 
-fn main() {
-    let input = parse_input(RAW);
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Input;
+    type Answer = usize;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
 }
 
+aoc_common::sample_tests!(Day8);
+
 #[cfg(test)]
 mod day8_tests {
     use std::{collections::HashSet, hash::RandomState};
 
     use super::*;
 
-    const SAMPLE: &str = include_str!("../sample.txt");
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
 
     #[test]
     fn test_parse_input() {
-        let input = parse_input(SAMPLE);
-        assert_eq!(
-            input.map.get(&(1, 8)).expect("does not exist").freq,
-            Some('0')
-        );
-        assert_eq!(
-            input.map.get(&(5, 6)).expect("does not exist").freq,
-            Some('A')
-        );
-        assert_eq!(input.map.get(&(5, 7)).expect("does not exist").freq, None);
+        let input = parse_input(SAMPLE).expect("could not parse sample");
+        assert_eq!(input.map[Coord::new(1, 8)].freq, Some('0'));
+        assert_eq!(input.map[Coord::new(5, 6)].freq, Some('A'));
+        assert_eq!(input.map[Coord::new(5, 7)].freq, None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("..a.\n.#..").expect_err("map should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "#"));
     }
 
     #[test_case::test_case((3, 4), (5, 5), (12, 12), vec![(7, 6), (1, 3)], AntinodeModel::Old ; "antinodes both on grid")]
     #[test_case::test_case((4,8), (5, 5), (10, 10), vec![(6,2)], AntinodeModel::Old ; "one antinode off grid")]
     #[test_case::test_case((9,8), (8,9), (10, 10), vec![], AntinodeModel::Old ; "two antinodes off grid")]
     fn test_antinode_locations(
-        c1: Coord,
-        c2: Coord,
+        c1: (i32, i32),
+        c2: (i32, i32),
         bounds: (usize, usize),
-        expected_antinodes: Vec<Coord>,
+        expected_antinodes: Vec<(i32, i32)>,
         model: AntinodeModel,
     ) {
-        let antinodes = antinode_locations(c1, c2, bounds, &model);
+        let grid = Grid::new(bounds.1, bounds.0, ());
+        let antinodes = antinode_locations(c1.into(), c2.into(), &grid, &model);
 
         let antinodes = HashSet::<_, RandomState>::from_iter(antinodes);
-        let expected = HashSet::<_, RandomState>::from_iter(expected_antinodes);
+        let expected =
+            HashSet::<_, RandomState>::from_iter(expected_antinodes.into_iter().map(Coord::from));
 
         assert_eq!(antinodes, expected);
     }
-
-    #[test]
-    fn test_part1() {
-        let input = parse_input(SAMPLE);
-        let expected = 14;
-
-        assert_eq!(part1(&input), expected);
-    }
-
-    #[test]
-    fn test_part2() {
-        let input = parse_input(SAMPLE);
-        let expected = 34;
-
-        assert_eq!(part2(&input), expected);
-    }
 }
@@ -1,7 +1,8 @@
 use std::{fmt::Display, iter::repeat_n};
 
-const RAW: &str = include_str!("../input.txt");
-type Input = Vec<usize>;
+use aoc_common::{parse::parse_token, ParseError, Solution};
+
+pub type Input = Vec<usize>;
 
 enum Segment {
     File { id: usize, size: usize },
@@ -29,15 +30,15 @@ enum DefragMode {
 
 type DiskMap = Vec<Segment>;
 
-fn parse_input(raw: &str) -> Input {
+fn parse_input(raw: &str) -> Result<Input, ParseError> {
     // Assume one line
-    raw.chars()
-        .map(|c| {
-            c.to_string()
-                .parse::<usize>()
-                .expect("could not parse char as usize")
-        })
-        .collect::<Vec<_>>()
+    let line = raw.trim_end();
+    if line.is_empty() {
+        return Err(ParseError::eof(raw, "a disk map"));
+    }
+    line.char_indices()
+        .map(|(idx, c)| parse_token(raw, &line[idx..idx + c.len_utf8()], "a digit"))
+        .collect()
 }
 
 fn create_disk_map(input: Input) -> DiskMap {
@@ -184,18 +185,33 @@ fn part2(input: &Input) -> usize {
     calculate_checksum(&map)
 }
 
-fn main() {
-    let input = parse_input(RAW);
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
 }
 
+aoc_common::sample_tests!(Day9);
+
 #[cfg(test)]
 mod day9_tests {
     use super::*;
 
-    const SAMPLE: &str = include_str!("../sample.txt");
+    const SAMPLE: &str = include_str!("../samples/sample.txt");
 
     fn render_disk_map(map: &DiskMap) -> String {
         String::from_iter(map.iter().map(ToString::to_string))
@@ -203,24 +219,42 @@ mod day9_tests {
 
     #[test]
     fn test_parse_input() {
-        let input = parse_input(SAMPLE);
+        let input = parse_input(SAMPLE).expect("could not parse sample");
         let expected = vec![2, 3, 3, 3, 1, 3, 3, 1, 2, 1, 4, 1, 4, 1, 3, 1, 4, 0, 2];
         assert_eq!(input, expected);
     }
 
+    #[test]
+    fn test_parse_input_trailing_newline() {
+        assert_eq!(parse_input("12345\n"), Ok(vec![1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("123-5").expect_err("disk map should be invalid");
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 4, "-"));
+    }
+
+    #[test_case::case("" ; "empty")]
+    #[test_case::case(" \n" ; "whitespace only")]
+    fn test_parse_error_empty(raw: &str) {
+        let err = parse_input(raw).expect_err("empty disk map should be invalid");
+        assert_eq!(err.expected, "a disk map");
+    }
+
     #[test_case::case("12345" => "0..111....22222".to_owned())]
     #[test_case::case("101010101" => "01234".to_owned())]
     #[test_case::case("10101010101010101010" => "0123456789".to_owned())]
     #[test_case::case("1010101010101010101010101" => "0123456789101112".to_owned())]
     fn test_render_disk_map(raw: &str) -> String {
-        let input = parse_input(raw);
+        let input = parse_input(raw).expect("could not parse disk map");
         let disk_map = create_disk_map(input);
         render_disk_map(&disk_map)
     }
 
     #[test]
     fn test_defrag_map() {
-        let input = parse_input("12345");
+        let input = parse_input("12345").expect("could not parse disk map");
         let mut disk_map = create_disk_map(input);
         defrag_disk_map(&mut disk_map, &DefragMode::Part);
         assert_eq!(render_disk_map(&disk_map), "022111222".to_owned())
@@ -238,20 +272,4 @@ mod day9_tests {
         left[gap_idx..gap_idx + len].swap_with_slice(&mut right[0..len]);
         assert_eq!(my_vec, expected);
     }
-
-    #[test]
-    fn test_part1() {
-        let input = parse_input(SAMPLE);
-        let expected = 1928;
-
-        assert_eq!(part1(&input), expected);
-    }
-
-    #[test]
-    fn test_part2() {
-        let input = parse_input(SAMPLE);
-        let expected = 2858;
-
-        assert_eq!(part2(&input), expected);
-    }
 }